@@ -1,14 +1,43 @@
+use chrono::{DateTime, Datelike, Local, NaiveDate};
 use notify_rust::Notification;
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 use std::time::{Duration, Instant};
 
+use crate::clipboard;
+use crate::config::{Bounds, Config, Rounding, Urgency};
+use crate::guardian;
+use crate::pair::SyncMessage;
+use crate::quotes;
+use crate::sound;
+use crate::stats::{self, Interruption, SessionRecord};
+use crate::status;
+use crate::ui_state::UiState;
+
+/// How long a flash message stays in the footer.
+const FLASH_DURATION: Duration = Duration::from_secs(3);
+
+/// Break time skipped per press of [F] (holding the key repeats it).
+const FAST_FORWARD_STEP: Duration = Duration::from_secs(30);
+
+/// After a phase change or reset, the auto-transition holds off this long, so
+/// stale timer state can't end the new phase in the same frame.
+const AUTO_COMPLETE_GUARD: Duration = Duration::from_secs(1);
+
+/// Overruns shorter than this are just normal loop latency and go unmentioned.
+const LATE_TRANSITION_NOTICE: Duration = Duration::from_secs(60);
+
+/// Task names [T] cycles through, most recently used first.
+const MAX_RECENT_TASKS: usize = 5;
+
 // --- Enums for State Management ---
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Phase {
     Focus,
     ShortBreak,
     LongBreak,
+    CustomBreak, // Ad-hoc break (e.g. lunch) outside the regular cycle
 }
 
 impl Phase {
@@ -17,6 +46,17 @@ impl Phase {
             Phase::Focus => "FOCUS SESSION",
             Phase::ShortBreak => "SHORT BREAK",
             Phase::LongBreak => "LONG BREAK",
+            Phase::CustomBreak => "CUSTOM BREAK",
+        }
+    }
+
+    /// Compact name for tables, e.g. "Short Break".
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Phase::Focus => "Focus",
+            Phase::ShortBreak => "Short Break",
+            Phase::LongBreak => "Long Break",
+            Phase::CustomBreak => "Custom",
         }
     }
 
@@ -25,23 +65,95 @@ impl Phase {
             Phase::Focus => Color::Red,
             Phase::ShortBreak => Color::Green,
             Phase::LongBreak => Color::Blue,
+            Phase::CustomBreak => Color::Yellow,
         }
     }
+
+    /// Fill symbol for the progress gauge, so phases are distinguishable at a glance.
+    pub fn gauge_symbol(&self, ascii: bool) -> &'static str {
+        match (self, ascii) {
+            (Phase::Focus, false) => "█",
+            (Phase::ShortBreak, false) => "▒",
+            (Phase::LongBreak | Phase::CustomBreak, false) => "░",
+            (Phase::Focus, true) => "#",
+            (Phase::ShortBreak, true) => "=",
+            (Phase::LongBreak | Phase::CustomBreak, true) => "-",
+        }
+    }
+
+    pub fn is_break(&self) -> bool {
+        matches!(
+            self,
+            Phase::ShortBreak | Phase::LongBreak | Phase::CustomBreak
+        )
+    }
 }
 
+/// What the Space key acts on: a fresh phase, a running timer, or a paused one.
 #[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TimerState {
+    Ready,
+    Running,
+    Paused,
+}
+
+impl TimerState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimerState::Ready => "READY",
+            TimerState::Running => "RUNNING",
+            TimerState::Paused => "PAUSED",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum AppTab {
     Timer,
     Settings,
+    Stats,
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[allow(clippy::enum_variant_names)]
 pub enum SettingSelection {
     FocusTime,
     ShortBreakTime,
     LongBreakTime,
 }
 
+// --- Tutorial ---
+
+/// First-run walkthrough steps: (title, body).
+pub const TUTORIAL_STEPS: &[(&str, &str)] = &[
+    (
+        "Welcome",
+        "The Pomodoro Technique splits your work into focused sessions separated by short breaks.",
+    ),
+    (
+        "Focus",
+        "Pick one task and work on it for a Focus session (25 min by default). Press [Space] to start or pause the timer.",
+    ),
+    (
+        "Breaks",
+        "After each Focus session take a Short Break. Every 4 sessions, take a Long Break. [N] skips ahead, [R] resets.",
+    ),
+    (
+        "Settings",
+        "Press [Tab] to open Settings and adjust durations with the arrow keys. [Q] quits at any time.",
+    ),
+];
+
+/// True when the whole-minute remaining value went down since the last check.
+pub fn minute_boundary_crossed(prev_minutes: Option<u64>, minutes: u64) -> bool {
+    prev_minutes.is_some_and(|prev| minutes < prev)
+}
+
+/// Auto-quit: the timer has been stopped with no input for `limit_mins` (0 = never).
+pub fn idle_quit_due(idle: Duration, limit_mins: u64) -> bool {
+    limit_mins > 0 && idle >= Duration::from_secs(limit_mins * 60)
+}
+
 // --- Main Application Struct ---
 
 pub struct App {
@@ -53,46 +165,282 @@ pub struct App {
     pub running: bool,
     pub start_time: Instant,
     pub paused_duration: Duration, // Accumulated time passed before pause
+    pub reset_at: Instant,         // Last phase change or reset (see `AUTO_COMPLETE_GUARD`)
+    pub fast_forward: Duration,    // Break time skipped with [F] (not counted as active)
+    pub over_plan: Duration,       // Overrun the phase already had when synced or reloaded
+    pub session_started_at: Option<DateTime<Local>>, // Wall-clock time of the first start in this phase
+    pub distractions: Vec<String>,                   // Distracting windows seen during this phase
+    pub interruptions: Vec<Interruption>,            // [I] presses during this phase
+    pub interrupted: bool,                           // Paused by [I] and not resumed yet
+    pub interrupt_prompt: bool,                      // Asking for the latest interruption's reason
+    pub resume_task_prompt: bool,                    // Offering `last_task` from the previous run
+    pub last_task: Option<String>,                   // Task of the previous run (see `ui_state`)
+    pub break_quote: Option<String>,                 // Picked once per break (`break_quotes`)
+
+    // Plain Timer: one countdown (`cfg_plain`) that stops when done, no phases
+    pub plain: bool,
+    pub plain_forced: bool, // `--plain` given: stays on whatever the config says
+
+    // Task being worked on (shown on the timer and logged with each session)
+    pub current_task: Option<String>,
+    pub recent_tasks: Vec<String>, // MRU, seeded from the session log
 
     // Pomodoro Logic
     pub pomodoro_count: u8, // Tracks completed focus sessions (0 to 3 before Long Break)
     pub long_break_interval: u8, // Define the interval for a long break (e.g., 4 sessions)
+    pub custom_break_index: usize, // Entry of `config.custom_breaks` used by Phase::CustomBreak
 
     // Configuration (stored in minutes)
     pub cfg_focus: u64,
     pub cfg_short: u64,
     pub cfg_long: u64,
+    pub cfg_plain: u64,
 
     // Settings Selection
     pub selected_setting: SettingSelection,
+    pub clamp_hint: Option<(&'static str, Instant)>, // Adjusted against a limit ("min"/"max")
+
+    // Round celebration: when to switch back from the Stats tab
+    pub stats_return_at: Option<Instant>,
+
+    // Long Break confirmation prompt is waiting for an answer
+    pub long_break_prompt: bool,
+
+    // Reset-to-defaults confirmation (Ctrl+Shift+R)
+    pub reset_prompt: bool,
+
+    // Day the daily goal was reached (celebrated once per day)
+    pub goal_celebrated: Option<NaiveDate>,
+
+    // Day the first-focus reminder was last sent (or found unneeded)
+    pub first_focus_reminded: Option<NaiveDate>,
+
+    // Deep Work: focus sessions chain (and auto-start) until the long break
+    pub deep_work: bool,
+
+    // Day the rest debt nudge was last sent (it fires once per day)
+    pub rest_debt_warned: Option<NaiveDate>,
+
+    // Last desktop notification, so the next one can replace it
+    pub notification_id: Option<u32>,
+
+    // Transient footer message (e.g. "Snapshot saved") and when it was set
+    pub flash: Option<(String, Instant)>,
+    pub quit_pressed_at: Option<Instant>, // First [Q] of a double press (`quit_grace_ms`)
+    pub snapshots_enabled: bool,          // `--snapshots`: [P] saves the current screen
+    pub snapshot_requested: bool,
+
+    // Display
+    pub gauge_inverted: bool, // Gauge drains as time runs out instead of filling up
+
+    // First-run tutorial (current step while the overlay is shown), shown until
+    // it has been closed once (see `ui_state`)
+    pub tutorial_step: Option<usize>,
+    pub tutorial_seen: bool,
+
+    // Loaded user configuration (feature flags, defaults)
+    pub config: Config,
+
+    // Session Log (every finished phase, oldest first)
+    pub history: Vec<SessionRecord>,
 }
 
 impl App {
-    pub fn new() -> Self {
+    pub fn new(config: Config, history: Vec<SessionRecord>) -> Self {
+        // Durations come from today's `[weekdays]` override, if any
+        let today = config.for_weekday(Local::now().weekday());
+
+        // A goal already reached before launch isn't celebrated again
+        let done_today = stats::pomodoros(&stats::today(&history)).count() as u32;
+        let goal_celebrated = (config.daily_goal > 0 && done_today >= config.daily_goal)
+            .then(|| Local::now().date_naive());
         Self {
             current_tab: AppTab::Timer,
             phase: Phase::Focus,
             running: false,
             start_time: Instant::now(),
             paused_duration: Duration::ZERO,
+            reset_at: Instant::now(),
+            fast_forward: Duration::ZERO,
+            over_plan: Duration::ZERO,
+            session_started_at: None,
+            distractions: Vec::new(),
+            interruptions: Vec::new(),
+            interrupted: false,
+            interrupt_prompt: false,
+            resume_task_prompt: false,
+            last_task: None,
+            break_quote: None,
+
+            plain: config.plain_timer,
+            plain_forced: false,
+
+            current_task: None,
+            recent_tasks: stats::recent_tasks(&history, MAX_RECENT_TASKS),
 
             pomodoro_count: 0,
-            long_break_interval: 4,
+            long_break_interval: today.long_break_interval,
+            custom_break_index: 0,
 
-            cfg_focus: 25,
-            cfg_short: 5,
-            cfg_long: 15,
+            cfg_focus: today.focus_mins,
+            cfg_short: today.short_break_mins,
+            cfg_long: today.long_break_mins,
+            cfg_plain: config.plain_timer_mins,
             selected_setting: SettingSelection::FocusTime,
+            clamp_hint: None,
+
+            stats_return_at: None,
+            long_break_prompt: false,
+            reset_prompt: false,
+            goal_celebrated,
+            first_focus_reminded: None,
+            deep_work: false,
+            rest_debt_warned: None,
+
+            notification_id: None,
+
+            flash: None,
+            quit_pressed_at: None,
+            snapshots_enabled: false,
+            snapshot_requested: false,
+
+            gauge_inverted: config.gauge_inverted,
+
+            tutorial_step: None,
+            tutorial_seen: false,
+
+            config,
+            history,
         }
     }
 
+    /// Strict Break: while a break is running under `strict_breaks`, the break
+    /// takes over the screen and only pausing (or quitting) is allowed.
+    pub fn is_locked_out(&self) -> bool {
+        self.config.strict_breaks && self.phase.is_break()
+    }
+
+    /// Shows a short-lived message in the footer in place of the key hints.
+    pub fn flash(&mut self, message: impl Into<String>) {
+        self.flash = Some((message.into(), Instant::now()));
+    }
+
+    /// [Q]: quits at once, or with `quit_grace_ms` set, only when pressed again
+    /// within that window (the first press just shows a hint).
+    pub fn confirm_quit(&mut self) -> bool {
+        let grace = Duration::from_millis(self.config.quit_grace_ms);
+        if grace.is_zero() || self.quit_pressed_at.is_some_and(|at| at.elapsed() <= grace) {
+            return true;
+        }
+        self.quit_pressed_at = Some(Instant::now());
+        self.flash("Press q again to quit");
+        false
+    }
+
+    pub fn flash_message(&self) -> Option<&str> {
+        self.flash
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < FLASH_DURATION)
+            .map(|(message, _)| message.as_str())
+    }
+
+    /// Key hints for the footer, limited to what the current tab and mode allow.
+    pub fn available_actions(&self) -> Vec<(&'static str, &'static str)> {
+        if self.tutorial_step.is_some() {
+            return vec![("Left/Right", "Navigate"), ("Esc", "Skip"), ("Q", "Quit")];
+        }
+        if self.resume_task_prompt {
+            return vec![("Y", "Continue Task"), ("N", "New Session"), ("Q", "Quit")];
+        }
+        if self.interrupt_prompt {
+            return vec![("1-9", "Reason"), ("Esc", "No Reason"), ("Q", "Quit")];
+        }
+        if self.reset_prompt {
+            return vec![
+                ("Y", "Reset"),
+                ("D", "Reset & Delete Config"),
+                ("Esc", "Cancel"),
+                ("Q", "Quit"),
+            ];
+        }
+        if self.long_break_prompt {
+            return vec![("S", "Skip Break"), ("Enter", "Start Break"), ("Q", "Quit")];
+        }
+        if self.is_locked_out() {
+            let toggle = match self.timer_state() {
+                TimerState::Ready => "Start",
+                TimerState::Running => "Pause",
+                TimerState::Paused => "Resume",
+            };
+            return vec![("Space", toggle), ("Q", "Quit")];
+        }
+
+        let mut actions = Vec::new();
+        match self.current_tab {
+            AppTab::Timer => {
+                actions.push(("Space", "Toggle"));
+                if self.running {
+                    actions.push(("I", "Interrupt"));
+                }
+                actions.push(("R", "Reset"));
+                // Plain Timer has no phases to switch between
+                if !self.plain {
+                    actions.push(("N", "Next Phase"));
+                    if self.phase.is_break() {
+                        actions.push(("F", "Fast-Forward"));
+                    }
+                    actions.push(("1/2/3", "Set Phase"));
+                    actions.push(("B", "Custom Break"));
+                    actions.push((
+                        "D",
+                        if self.deep_work {
+                            "End Deep Work"
+                        } else {
+                            "Deep Work"
+                        },
+                    ));
+                }
+                actions.push(("G", "Flip Gauge"));
+                if self.can_cycle_task() {
+                    actions.push(("T", "Next Task"));
+                }
+                actions.push(("Tab", "Settings"));
+            }
+            AppTab::Settings => {
+                if !self.plain {
+                    actions.push(("Up/Down", "Select"));
+                }
+                actions.push(("Left/Right", "Adjust (±5m)"));
+                actions.push(("E", "Edit Config"));
+                actions.push(("Tab", "Stats"));
+            }
+            AppTab::Stats => {
+                actions.push(("C", "Copy Summary"));
+                actions.push(("Tab", "Back to Timer"));
+            }
+        }
+        if self.snapshots_enabled {
+            actions.push(("P", "Snapshot"));
+        }
+        actions.push(("Q", "Quit"));
+        actions
+    }
+
     // --- Time Logic ---
 
     pub fn get_target_duration(&self) -> Duration {
+        if self.plain {
+            return Duration::from_secs(self.cfg_plain * 60);
+        }
         let mins = match self.phase {
             Phase::Focus => self.cfg_focus,
             Phase::ShortBreak => self.cfg_short,
             Phase::LongBreak => self.cfg_long,
+            Phase::CustomBreak => self
+                .config
+                .custom_breaks
+                .get(self.custom_break_index)
+                .map_or(self.cfg_short, |custom| custom.mins),
         };
         Duration::from_secs(mins * 60)
     }
@@ -107,52 +455,749 @@ impl App {
 
     pub fn get_remaining(&self) -> Duration {
         let target = self.get_target_duration();
-        target.saturating_sub(self.get_elapsed())
+        target.saturating_sub(self.get_elapsed() + self.fast_forward)
     }
 
-    pub fn toggle_timer(&mut self) {
+    /// How far the timer has run past the end of the phase (e.g. when the render
+    /// loop was starved while the terminal was suspended).
+    pub fn get_overrun(&self) -> Duration {
+        self.get_elapsed()
+            .saturating_sub(self.get_target_duration())
+    }
+
+    /// The part of the overrun that built up while the phase was due but hadn't
+    /// ended yet (see `next_phase`). A phase synced or reloaded while already
+    /// past its plan really ran that long, so that part doesn't count.
+    fn late_overrun(&self) -> Duration {
+        self.get_overrun().saturating_sub(self.over_plan)
+    }
+
+    /// Whole minutes left, rounded up: the value only drops as each minute passes
+    /// (24:00, 23:00, ...), which is when the minute tick fires.
+    pub fn remaining_minutes(&self) -> u64 {
+        self.get_remaining().as_secs().div_ceil(60)
+    }
+
+    /// Minute tick: only during a running focus session, if enabled and not
+    /// silenced (muted or quiet hours).
+    pub fn minute_tick_enabled(&self) -> bool {
+        self.config.minute_tick
+            && !self.config.silenced(Local::now().time())
+            && self.running
+            && self.phase == Phase::Focus
+    }
+
+    /// Gauge fill ratio: elapsed/total, or remaining/total when inverted.
+    pub fn gauge_ratio(&self) -> f64 {
+        let total = self.get_target_duration().as_secs_f64();
+        let shown = if self.gauge_inverted {
+            self.get_remaining()
+        } else {
+            self.get_target_duration() - self.get_remaining()
+        };
+        (shown.as_secs_f64() / total).clamp(0.0, 1.0)
+    }
+
+    /// Whole seconds left as shown on screen. `Floor` drops the partial second
+    /// (starts at 24:59, lingers on 00:00); `Ceil` keeps it (starts at 25:00 and
+    /// shows 00:01 until the phase is actually over). The auto-transition fires
+    /// when the real remaining time hits zero, so with `Ceil` 00:00 is never shown
+    /// for a running phase.
+    pub fn remaining_display_secs(&self) -> u64 {
+        let remaining = self.get_remaining();
+        match self.config.countdown_rounding {
+            Rounding::Floor => remaining.as_secs(),
+            Rounding::Ceil => remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0),
+        }
+    }
+
+    /// Remaining time formatted as "MM:SS".
+    pub fn remaining_clock(&self) -> String {
+        let secs = self.remaining_display_secs();
+        format!("{:02}:{:02}", secs / 60, secs % 60)
+    }
+
+    /// The running phase is over and should transition. Never fires right after
+    /// a (manual) phase change, see `AUTO_COMPLETE_GUARD`.
+    pub fn auto_complete_due(&self) -> bool {
+        self.running
+            && self.get_remaining().is_zero()
+            && self.reset_at.elapsed() >= AUTO_COMPLETE_GUARD
+    }
+
+    pub fn timer_state(&self) -> TimerState {
         if self.running {
-            // Pause
-            self.paused_duration += self.start_time.elapsed();
-            self.running = false;
+            TimerState::Running
+        } else if self.paused_duration.is_zero() {
+            TimerState::Ready
         } else {
-            // Resume
-            self.start_time = Instant::now();
-            self.running = true;
+            TimerState::Paused
         }
     }
 
+    /// Space: READY starts a fresh countdown, RUNNING pauses, PAUSED resumes.
+    pub fn toggle_timer(&mut self) {
+        match self.timer_state() {
+            TimerState::Ready => {
+                self.paused_duration = Duration::ZERO;
+                self.start_time = Instant::now();
+                self.running = true;
+                self.session_started_at = Some(Local::now());
+            }
+            TimerState::Running => {
+                self.paused_duration += self.start_time.elapsed();
+                self.running = false;
+            }
+            TimerState::Paused => {
+                self.start_time = Instant::now();
+                self.running = true;
+                self.interrupted = false;
+                self.session_started_at.get_or_insert_with(Local::now);
+            }
+        }
+    }
+
+    /// `--remaining`: leaves `remaining` on the clock by counting the rest of the
+    /// phase as already elapsed (paused).
+    pub fn set_remaining(&mut self, remaining: Duration) -> std::io::Result<()> {
+        let target = self.get_target_duration();
+        if remaining > target {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "--remaining {}s exceeds the {} min {}",
+                    remaining.as_secs(),
+                    target.as_secs() / 60,
+                    self.phase_name().to_lowercase()
+                ),
+            ));
+        }
+        self.paused_duration = target - remaining;
+        Ok(())
+    }
+
+    /// Starts the current phase over. A phase with an [I] interruption is logged
+    /// (uncounted) first, so the interruption isn't lost with it.
     pub fn reset_timer(&mut self) {
+        self.log_if_interrupted();
         self.running = false;
         self.paused_duration = Duration::ZERO;
+        self.fast_forward = Duration::ZERO;
+        self.over_plan = Duration::ZERO;
         self.start_time = Instant::now();
+        self.reset_at = Instant::now();
+        self.session_started_at = None;
+        self.distractions.clear();
+        self.interruptions.clear();
+        self.interrupted = false;
+        self.interrupt_prompt = false;
+        self.break_quote = None;
+        if self.config.break_quotes && self.phase.is_break() && !self.plain {
+            // Numbered by the breaks logged so far, so a reset keeps the quote
+            let seed = self
+                .history
+                .iter()
+                .filter(|record| record.phase.is_break())
+                .count();
+            self.break_quote = quotes::pick(&quotes::load(&self.config), seed);
+        }
+    }
+
+    /// [1]/[2]/[3]: jumps straight to `phase`, starting it over.
+    pub fn set_phase(&mut self, phase: Phase) {
+        self.leave_phase(phase);
+        self.phase = phase;
+        self.reset_timer();
+    }
+
+    /// Logs the phase that [1]/[2]/[3] or [B] switches away from. As with [N], a
+    /// regular break is logged even if it never started (for the rest debt); a
+    /// focus session doesn't advance the cycle this way, so it isn't counted.
+    /// Staying in the same phase just starts it over.
+    fn leave_phase(&mut self, next: Phase) {
+        if next == self.phase || self.plain {
+            self.log_if_interrupted();
+            return;
+        }
+        self.log_session(self.phase.is_break());
+    }
+
+    /// Logs the current phase as interrupted if [I] was pressed during it. Called
+    /// before anything that starts the phase over or swaps it out.
+    fn log_if_interrupted(&mut self) {
+        if !self.interruptions.is_empty() && !self.plain {
+            self.log_session(false);
+        }
     }
 
     /// Core Pomodoro logic: Handles phase transition and updates the pomodoro count.
+    ///
+    /// Catch-up policy: a new phase never starts on its own, so however far the
+    /// timer overran (e.g. the app was backgrounded), only the current phase has
+    /// actually finished. We advance exactly once, log the phase as ending on
+    /// time, and mention the delay in the notification.
     pub fn next_phase(&mut self) {
+        let overrun = self.late_overrun();
+        let finished = self.phase;
+
+        let counted = self.focus_counts();
+        let short_break = if self.config.skip_short_breaks || self.deep_work {
+            Phase::Focus
+        } else {
+            Phase::ShortBreak
+        };
+
+        self.log_session(counted);
         self.phase = match self.phase {
+            Phase::Focus if !counted => {
+                // Interrupted: skipped too early to count as a pomodoro
+                self.flash(format!(
+                    "Focus interrupted: under {:.0}% done, not counted",
+                    self.config.min_focus_fraction * 100.0
+                ));
+                short_break
+            }
             Phase::Focus => {
                 self.pomodoro_count += 1;
-                if self.pomodoro_count % self.long_break_interval == 0 {
+                if self.pomodoro_count.is_multiple_of(self.long_break_interval) {
                     Phase::LongBreak
                 } else {
-                    Phase::ShortBreak
+                    short_break
                 }
             }
-            // Breaks always transition back to a Focus session
-            Phase::ShortBreak | Phase::LongBreak => Phase::Focus,
+            // Breaks always transition back to a Focus session (custom breaks
+            // sit outside the cycle, so they leave the count alone)
+            Phase::ShortBreak | Phase::LongBreak | Phase::CustomBreak => Phase::Focus,
+        };
+        self.reset_timer();
+
+        // Deep Work lasts one round: the long break ends it
+        if self.phase == Phase::LongBreak {
+            self.deep_work = false;
+        }
+
+        // Deep Work: the chained focus session carries on without waiting for [Space]
+        if self.deep_work && finished == Phase::Focus && self.phase == Phase::Focus {
+            self.toggle_timer();
+        }
+
+        // Round complete: show off the Stats tab for a moment
+        if finished == Phase::LongBreak && self.config.round_stats_secs > 0 {
+            self.current_tab = AppTab::Stats;
+            self.stats_return_at =
+                Some(Instant::now() + Duration::from_secs(self.config.round_stats_secs));
+        }
+
+        // Offer to skip the long break (never under Strict Break)
+        self.long_break_prompt = self.phase == Phase::LongBreak
+            && self.config.confirm_long_break
+            && !self.config.strict_breaks;
+
+        let alert = self.config.alerts.for_phase(self.phase).clone();
+        let mut body = status::format_tokens(&alert.body, &[("phase", self.phase_name())]);
+        if overrun >= LATE_TRANSITION_NOTICE {
+            body.push_str(&format!(
+                " ({} ended {} min ago)",
+                finished.name(),
+                overrun.as_secs() / 60
+            ));
+        }
+        self.show_notification(&alert.summary, &body, alert.urgency);
+        if let Some(sound) = &alert.sound
+            && !self.config.silenced(Local::now().time())
+        {
+            sound::play(sound);
+        }
+
+        if finished.is_break() {
+            self.check_rest_debt();
+        } else if counted {
+            self.check_daily_goal(Local::now());
+        }
+    }
+
+    /// Daily goal celebration: the first time the pomodoros on `now`'s day reach
+    /// `daily_goal`, notifies and plays `goal_sound` (or the bell), unless muted
+    /// or in quiet hours. Returns whether the goal was celebrated.
+    fn check_daily_goal(&mut self, now: DateTime<Local>) -> bool {
+        let goal = self.config.daily_goal;
+        let today = now.date_naive();
+        if goal == 0 || self.goal_celebrated == Some(today) {
+            return false;
+        }
+
+        let done = stats::pomodoros(&self.history)
+            .filter(|record| record.ended_at.date_naive() == today)
+            .count() as u32;
+        if done < goal {
+            return false;
+        }
+        self.goal_celebrated = Some(today);
+        self.notify(
+            "Daily Goal Reached",
+            &format!("{} pomodoros today, well done!", done),
+        );
+        if !self.config.silenced(now.time()) {
+            match &self.config.goal_sound {
+                Some(sound) => sound::play(sound),
+                None => sound::bell(),
+            }
+        }
+        true
+    }
+
+    /// Rest debt nudge: notifies (once per day) when the break time skipped today
+    /// reaches `rest_debt_mins`. Returns whether the nudge went out.
+    fn check_rest_debt(&mut self) -> bool {
+        let threshold = Duration::from_secs(self.config.rest_debt_mins * 60);
+        let today = Local::now().date_naive();
+        if threshold.is_zero() || self.rest_debt_warned == Some(today) {
+            return false;
+        }
+
+        let debt = stats::rest_debt(&stats::today(&self.history));
+        if debt < threshold {
+            return false;
+        }
+        self.rest_debt_warned = Some(today);
+        self.notify(
+            "Take a Break?",
+            &format!(
+                "You've skipped {} of breaks today, take one?",
+                stats::format_minutes(debt)
+            ),
+        );
+        true
+    }
+
+    /// Plain Timer: the countdown ran out. Notify and stop, ready to run again;
+    /// nothing is logged and no phase follows.
+    pub fn finish_countdown(&mut self) {
+        self.reset_timer();
+        let name = self.phase_name();
+        self.flash(format!("{} finished", name));
+        self.notify("Timer Finished", &format!("{} is done", name));
+    }
+
+    /// [I]: something urgent came up. Pauses the running session, records the
+    /// interruption and asks for a quick reason tag.
+    pub fn interrupt(&mut self) {
+        if !self.running {
+            return;
+        }
+        self.toggle_timer();
+        self.interrupted = true;
+        self.interruptions.push(Interruption {
+            at: Local::now(),
+            reason: None,
+        });
+        self.interrupt_prompt = !self.config.interrupt_reasons.is_empty();
+        if !self.interrupt_prompt {
+            self.flash("Interrupted: resume with Space");
+        }
+    }
+
+    /// Tags the latest interruption with `interrupt_reasons[index]` (`None` skips).
+    pub fn tag_interruption(&mut self, index: Option<usize>) {
+        let reason = index.and_then(|index| self.config.interrupt_reasons.get(index));
+        if index.is_some() && reason.is_none() {
+            return; // Not one of the offered tags
+        }
+        if let Some(last) = self.interruptions.last_mut() {
+            last.reason = reason.cloned();
+        }
+        self.interrupt_prompt = false;
+        self.flash("Interrupted: resume with Space");
+    }
+
+    /// [C] on the Stats tab: copies a one-line summary of today to the clipboard.
+    pub fn copy_summary(&mut self) {
+        let summary = stats::summary(&self.history, self.config.ascii);
+        match clipboard::copy(&summary) {
+            Ok(()) => self.flash(format!("Copied: {}", summary)),
+            Err(err) => self.flash(format!("Clipboard unavailable: {}", err)),
+        }
+    }
+
+    /// [D]: toggles Deep Work. Short breaks are skipped while it's on, so the
+    /// focus sessions of a round run back-to-back until the long break, which
+    /// turns it off again.
+    pub fn toggle_deep_work(&mut self) {
+        self.deep_work = !self.deep_work;
+        self.flash(if self.deep_work {
+            "Deep Work on: focus sessions chain until the long break"
+        } else {
+            "Deep Work off"
+        });
+    }
+
+    /// [F]: winds a break down by `FAST_FORWARD_STEP`, ending it (and moving on)
+    /// once nothing is left. Does nothing during focus.
+    pub fn fast_forward_break(&mut self) {
+        if !self.phase.is_break() || self.plain {
+            return;
+        }
+        self.fast_forward += FAST_FORWARD_STEP.min(self.get_remaining());
+        if self.get_remaining().is_zero() {
+            self.next_phase();
+        }
+    }
+
+    /// First-focus reminder: once `first_focus_by` has passed without a focus
+    /// session started today, nudges once (per day) to get going. Returns
+    /// whether the reminder went out.
+    pub fn check_first_focus(&mut self, now: DateTime<Local>) -> bool {
+        let today = now.date_naive();
+        if !self.config.first_focus_reminder
+            || self.first_focus_reminded == Some(today)
+            || now.time() < self.config.first_focus_by
+        {
+            return false;
+        }
+
+        let focus_started = self.phase == Phase::Focus
+            && self
+                .session_started_at
+                .is_some_and(|at| at.date_naive() == today);
+        let focused_today = self
+            .history
+            .iter()
+            .any(|record| record.phase == Phase::Focus && record.ended_at.date_naive() == today);
+
+        self.first_focus_reminded = Some(today);
+        if focus_started || focused_today {
+            return false;
+        }
+        self.notify("Good Morning", "Start your first pomodoro!");
+        true
+    }
+
+    /// Returns to the Timer tab once the round-completion Stats display is over.
+    pub fn check_stats_return(&mut self) {
+        if self.stats_return_at.is_some_and(|at| Instant::now() >= at) {
+            self.stats_return_at = None;
+            self.current_tab = AppTab::Timer;
+        }
+    }
+
+    /// Whether ending the current phase now counts it as a finished pomodoro: the
+    /// timer ran out, or at least `min_focus_fraction` of it elapsed before [N].
+    pub fn focus_counts(&self) -> bool {
+        if self.phase != Phase::Focus || self.get_remaining().is_zero() {
+            return true;
+        }
+        let elapsed = self.get_elapsed().as_secs_f64();
+        let target = self.get_target_duration().as_secs_f64();
+        elapsed / target >= self.config.min_focus_fraction
+    }
+
+    /// Long Break prompt: [Enter] starts the break right away.
+    pub fn start_long_break(&mut self) {
+        self.long_break_prompt = false;
+        self.toggle_timer();
+    }
+
+    /// Long Break prompt: [S] skips straight to the next focus session. The
+    /// pomodoro count is kept, so the next long break is a full interval away.
+    pub fn skip_long_break(&mut self) {
+        self.long_break_prompt = false;
+        self.log_session(true);
+        self.phase = Phase::Focus;
+        self.reset_timer();
+        self.check_rest_debt();
+    }
+
+    /// Starts an ad-hoc break from `config.custom_breaks`. Pressing again while a
+    /// custom break is up cycles to the next configured entry.
+    pub fn start_custom_break(&mut self) {
+        let count = self.config.custom_breaks.len();
+        if count == 0 {
+            return;
+        }
+
+        self.custom_break_index = if self.phase == Phase::CustomBreak {
+            (self.custom_break_index + 1) % count
+        } else {
+            0
         };
+        self.leave_phase(Phase::CustomBreak);
+        self.phase = Phase::CustomBreak;
         self.reset_timer();
-        self.notify("Phase Changed", &format!("Starting {}", self.phase.name()));
     }
 
-    pub fn notify(&self, title: &str, body: &str) {
-        let _ = Notification::new().summary(title).body(body).show();
+    /// Display name of the current phase, using the custom break's own name.
+    pub fn phase_name(&self) -> String {
+        if self.plain {
+            return self.config.plain_timer_name.to_uppercase();
+        }
+        match self.config.custom_breaks.get(self.custom_break_index) {
+            Some(custom) if self.phase == Phase::CustomBreak => custom.name.to_uppercase(),
+            _ => self.phase.name().to_string(),
+        }
+    }
+
+    /// Records the phase that is ending in the session log. Phases that were
+    /// never started (e.g. skipped straight away) are not logged, except regular
+    /// breaks: a skipped break is logged with no active time, for the rest debt.
+    fn log_session(&mut self, counted: bool) {
+        let skipped_break = matches!(self.phase, Phase::ShortBreak | Phase::LongBreak);
+        let Some(started_at) = self
+            .session_started_at
+            .or_else(|| skipped_break.then(Local::now))
+        else {
+            return;
+        };
+
+        // A late transition happened after the phase was over: clamp it off
+        let late = self.late_overrun();
+        let mut record = SessionRecord::new(
+            self.phase,
+            started_at,
+            Local::now() - chrono::Duration::from_std(late).unwrap_or_default(),
+            self.get_target_duration(),
+            self.get_elapsed().saturating_sub(late),
+            self.get_remaining().is_zero(),
+        );
+        record.distractions = std::mem::take(&mut self.distractions);
+        record.interruptions = std::mem::take(&mut self.interruptions);
+        record.profile = self.config.profile.clone();
+        record.counted = counted;
+        record.task = self.current_task.clone();
+        if let Some(task) = record.task.clone() {
+            self.remember_task(task);
+        }
+        let _ = stats::append(&record);
+        self.history.push(record);
+    }
+
+    /// Focus Guardian: called with each new active window title. Switching to a
+    /// blocklisted window during a running focus session warns and is logged.
+    pub fn check_active_window(&mut self, title: &str) {
+        if self.plain || self.phase != Phase::Focus || !self.running {
+            return;
+        }
+        if guardian::is_distracting(title, &self.config.distraction_blocklist) {
+            self.notify("Stay Focused", &format!("Distraction detected: {}", title));
+            self.distractions.push(title.to_string());
+        }
+    }
+
+    /// Sends a desktop notification at normal urgency.
+    pub fn notify(&mut self, title: &str, body: &str) {
+        self.show_notification(title, body, Urgency::Normal);
+    }
+
+    /// Sends a desktop notification. With `replace_notifications`, it replaces the
+    /// previous one instead of stacking up in the notification center (only the
+    /// freedesktop backend reports ids; elsewhere notifications simply stack).
+    fn show_notification(&mut self, title: &str, body: &str, urgency: Urgency) {
+        if cfg!(test) {
+            return;
+        }
+        let mut notification = Notification::new();
+        notification.summary(title).body(body);
+        #[cfg(all(unix, not(target_os = "macos")))]
+        notification.urgency(match urgency {
+            Urgency::Low => notify_rust::Urgency::Low,
+            Urgency::Normal => notify_rust::Urgency::Normal,
+            Urgency::Critical => notify_rust::Urgency::Critical,
+        });
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        let _ = urgency;
+        if self.config.replace_notifications
+            && let Some(id) = self.notification_id
+        {
+            notification.id(id);
+        }
+
+        #[cfg(all(unix, not(target_os = "macos")))]
+        if let Ok(handle) = notification.show() {
+            self.notification_id = Some(handle.id());
+        }
+        #[cfg(not(all(unix, not(target_os = "macos"))))]
+        let _ = notification.show();
+    }
+
+    /// Pair mode: mirrors the peer's phase and timer. A phase of ours that was
+    /// under way is logged as it ends, like a manual skip.
+    pub fn apply_sync(&mut self, message: &SyncMessage) {
+        if message.phase != self.phase {
+            let counted = self.focus_counts();
+            self.log_session(counted);
+            self.phase = message.phase;
+            self.reset_timer();
+            self.long_break_prompt = false;
+        }
+        self.pomodoro_count = message.pomodoro_count;
+
+        self.paused_duration = message.elapsed_now();
+        self.start_time = Instant::now();
+        self.over_plan = self.get_overrun();
+        self.running = message.running;
+        if self.running {
+            self.session_started_at.get_or_insert_with(Local::now);
+        }
+    }
+
+    // --- Tutorial Logic ---
+
+    pub fn next_tutorial_step(&mut self) {
+        if let Some(step) = self.tutorial_step {
+            if step + 1 < TUTORIAL_STEPS.len() {
+                self.tutorial_step = Some(step + 1);
+            } else {
+                self.finish_tutorial();
+            }
+        }
+    }
+
+    pub fn prev_tutorial_step(&mut self) {
+        if let Some(step) = self.tutorial_step {
+            self.tutorial_step = Some(step.saturating_sub(1));
+        }
+    }
+
+    /// Closes the tutorial (completed or skipped) and remembers it in the UI
+    /// state right away, so it isn't shown again even if the app crashes.
+    pub fn finish_tutorial(&mut self) {
+        self.tutorial_step = None;
+        self.tutorial_seen = true;
+        let _ = self.save_ui_state();
+    }
+
+    /// Reopens the tab and setting of the previous run; anything missing (or
+    /// no longer valid) falls back to the Timer tab and Focus setting. Starts
+    /// the tutorial on the first run.
+    pub fn restore_ui_state(&mut self, state: UiState) {
+        self.current_tab = state.last_tab.unwrap_or(AppTab::Timer);
+        self.selected_setting = state.last_setting.unwrap_or(SettingSelection::FocusTime);
+        self.last_task = state.last_task;
+        self.tutorial_seen = state.tutorial_seen;
+        self.tutorial_step = (!state.tutorial_seen).then_some(0);
+    }
+
+    pub fn ui_state(&self) -> UiState {
+        UiState {
+            last_tab: Some(self.current_tab),
+            last_setting: Some(self.selected_setting),
+            last_task: self.current_task.clone(),
+            tutorial_seen: self.tutorial_seen,
+        }
+    }
+
+    /// Remembers the current tab, setting and task for the next launch.
+    pub fn save_ui_state(&self) -> std::io::Result<()> {
+        crate::ui_state::save(&self.ui_state())
+    }
+
+    /// On launch without `--task`: offer to carry on with the previous run's task.
+    pub fn offer_last_task(&mut self) {
+        self.resume_task_prompt = self.current_task.is_none()
+            && self
+                .last_task
+                .as_ref()
+                .is_some_and(|task| !task.trim().is_empty());
+    }
+
+    /// Answers the resume prompt: yes labels the coming sessions with the last task.
+    pub fn resume_last_task(&mut self, resume: bool) {
+        if resume {
+            self.current_task = self.last_task.clone();
+            if let Some(task) = self.current_task.clone() {
+                self.remember_task(task);
+            }
+        }
+        self.resume_task_prompt = false;
+    }
+
+    /// Moves `task` to the front of the recent tasks.
+    pub fn remember_task(&mut self, task: String) {
+        self.recent_tasks.retain(|recent| *recent != task);
+        self.recent_tasks.insert(0, task);
+        self.recent_tasks.truncate(MAX_RECENT_TASKS);
+    }
+
+    fn can_cycle_task(&self) -> bool {
+        match &self.current_task {
+            Some(task) => self.recent_tasks.iter().any(|recent| recent != task),
+            None => !self.recent_tasks.is_empty(),
+        }
+    }
+
+    /// [T]: switches to the next of the recent tasks. Cycling leaves the order
+    /// alone; a task only moves to the front once a session is logged with it.
+    pub fn cycle_task(&mut self) {
+        if !self.can_cycle_task() {
+            return;
+        }
+        let next = self
+            .current_task
+            .as_ref()
+            .and_then(|task| self.recent_tasks.iter().position(|recent| recent == task))
+            .map_or(0, |index| (index + 1) % self.recent_tasks.len());
+        self.current_task = Some(self.recent_tasks[next].clone());
     }
 
     // --- Configuration Logic ---
 
+    /// Emergency reset: every setting goes back to its default, as in `new()`,
+    /// and the current phase starts over with the default durations (the
+    /// session log is left alone). With `delete_file`, the config file on disk
+    /// is removed as well.
+    pub fn reset_to_defaults(&mut self, delete_file: bool) {
+        self.reset_prompt = false;
+        self.log_if_interrupted();
+
+        let mut config = Config::default();
+        config.profile = self.config.profile.clone();
+        self.reload_config(config);
+        self.deep_work = false;
+        self.custom_break_index = 0;
+        self.selected_setting = SettingSelection::FocusTime;
+        self.clamp_hint = None;
+        self.reset_timer();
+
+        if delete_file
+            && let Some(Err(err)) =
+                Config::path(self.config.profile.as_deref()).map(std::fs::remove_file)
+            && err.kind() != std::io::ErrorKind::NotFound
+        {
+            self.flash(format!(
+                "Settings reset, but the config file stayed: {}",
+                err
+            ));
+            return;
+        }
+        self.flash("Settings reset to defaults");
+    }
+
+    /// Swaps in a config reloaded from disk. Durations adjusted on the Settings
+    /// tab (and a gauge flipped with [G]) are replaced by the file's values; the
+    /// current phase carries on, unless `plain_timer` switched modes.
+    pub fn reload_config(&mut self, config: Config) {
+        let today = config.for_weekday(Local::now().weekday());
+        self.cfg_focus = today.focus_mins;
+        self.cfg_short = today.short_break_mins;
+        self.cfg_long = today.long_break_mins;
+        self.cfg_plain = config.plain_timer_mins;
+        self.long_break_interval = today.long_break_interval;
+        self.gauge_inverted = config.gauge_inverted;
+        let plain = config.plain_timer || self.plain_forced;
+        self.config = config;
+        if plain != self.plain {
+            self.log_if_interrupted();
+            self.plain = plain;
+            self.reset_timer();
+        }
+        self.over_plan = self.get_overrun();
+        self.flash("Config reloaded");
+    }
+
     pub fn next_setting(&mut self) {
+        if self.plain {
+            return;
+        }
+        self.clamp_hint = None;
         self.selected_setting = match self.selected_setting {
             SettingSelection::FocusTime => SettingSelection::ShortBreakTime,
             SettingSelection::ShortBreakTime => SettingSelection::LongBreakTime,
@@ -161,6 +1206,10 @@ impl App {
     }
 
     pub fn prev_setting(&mut self) {
+        if self.plain {
+            return;
+        }
+        self.clamp_hint = None;
         self.selected_setting = match self.selected_setting {
             SettingSelection::FocusTime => SettingSelection::LongBreakTime,
             SettingSelection::ShortBreakTime => SettingSelection::FocusTime,
@@ -168,18 +1217,973 @@ impl App {
         };
     }
 
+    /// Adjusts the selected duration. Pressing against a limit leaves it (and
+    /// the timer) alone and shows "(min)"/"(max)" next to the value for a moment.
+    /// A value from the config file outside `[bounds]` only moves back toward
+    /// them, so a press never jumps it the other way.
     pub fn adjust_setting(&mut self, delta: i64) {
-        match self.selected_setting {
-            SettingSelection::FocusTime => {
-                self.cfg_focus = (self.cfg_focus as i64 + delta).max(1).min(120) as u64;
-            }
-            SettingSelection::ShortBreakTime => {
-                self.cfg_short = (self.cfg_short as i64 + delta).max(1).min(60) as u64;
-            }
-            SettingSelection::LongBreakTime => {
-                self.cfg_long = (self.cfg_long as i64 + delta).max(1).min(60) as u64;
-            }
+        let (value, range) = self.selected_duration();
+        let (min, max) = (range.min.min(*value), range.max.max(*value));
+        let adjusted = (*value as i64 + delta).clamp(min as i64, max as i64) as u64;
+        if adjusted == *value {
+            let limit = if delta > 0 { "max" } else { "min" };
+            self.clamp_hint = Some((limit, Instant::now()));
+            return;
         }
+
+        // Logged with the duration it was planned with
+        self.log_if_interrupted();
+        *self.selected_duration().0 = adjusted;
+        self.clamp_hint = None;
         self.reset_timer();
     }
+
+    /// The duration selected on the Settings tab, with its bounds.
+    fn selected_duration(&mut self) -> (&mut u64, Bounds) {
+        let bounds = &self.config.bounds;
+        match self.selected_setting {
+            _ if self.plain => (&mut self.cfg_plain, bounds.plain_timer),
+            SettingSelection::FocusTime => (&mut self.cfg_focus, bounds.focus),
+            SettingSelection::ShortBreakTime => (&mut self.cfg_short, bounds.short_break),
+            SettingSelection::LongBreakTime => (&mut self.cfg_long, bounds.long_break),
+        }
+    }
+
+    /// "min" or "max" while the clamp cue from `adjust_setting` is showing.
+    pub fn clamp_hint(&self) -> Option<&'static str> {
+        self.clamp_hint
+            .filter(|(_, at)| at.elapsed() < FLASH_DURATION)
+            .map(|(limit, _)| limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_app() -> App {
+        App::new(Config::default(), Vec::new())
+    }
+
+    fn mins(mins: u64) -> Duration {
+        Duration::from_secs(mins * 60)
+    }
+
+    #[test]
+    fn logged_session_separates_active_time_from_span() {
+        let mut app = test_app();
+        // Started 40 minutes ago, ran the full 25 with a 15 minute pause
+        app.session_started_at = Some(Local::now() - chrono::Duration::minutes(40));
+        app.paused_duration = mins(25);
+        app.next_phase();
+
+        let record = &app.history[0];
+        assert_eq!(record.active_secs, 25 * 60);
+        assert!((40 * 60..40 * 60 + 5).contains(&record.span_secs));
+    }
+
+    fn keys(app: &App) -> Vec<&'static str> {
+        app.available_actions()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    #[test]
+    fn footer_lists_every_timer_action_for_a_running_break() {
+        let mut app = test_app();
+        app.phase = Phase::ShortBreak;
+        app.toggle_timer();
+        app.snapshots_enabled = true;
+
+        assert_eq!(
+            keys(&app),
+            [
+                "Space", "I", "R", "N", "F", "1/2/3", "B", "D", "G", "Tab", "P", "Q"
+            ]
+        );
+    }
+
+    #[test]
+    fn footer_leaves_out_phase_keys_in_plain_mode() {
+        let mut app = test_app();
+        app.plain = true;
+        assert_eq!(keys(&app), ["Space", "R", "G", "Tab", "Q"]);
+
+        app.current_tab = AppTab::Settings;
+        assert_eq!(keys(&app), ["Left/Right", "E", "Tab", "Q"]);
+    }
+
+    #[test]
+    fn footer_offers_only_pause_and_quit_during_a_strict_break() {
+        let mut app = test_app();
+        app.config.strict_breaks = true;
+        app.phase = Phase::LongBreak;
+        assert_eq!(app.available_actions(), [("Space", "Start"), ("Q", "Quit")]);
+
+        app.toggle_timer();
+        assert_eq!(app.available_actions(), [("Space", "Pause"), ("Q", "Quit")]);
+    }
+
+    #[test]
+    fn footer_follows_the_open_prompt() {
+        let mut app = test_app();
+        app.toggle_timer();
+        app.interrupt();
+        assert_eq!(keys(&app), ["1-9", "Esc", "Q"]);
+
+        app.tag_interruption(None);
+        app.long_break_prompt = true;
+        assert_eq!(keys(&app), ["S", "Enter", "Q"]);
+    }
+
+    #[test]
+    fn countdown_rounds_partial_seconds_as_configured() {
+        let mut app = test_app();
+        // 59.5 seconds left
+        app.paused_duration = mins(25) - Duration::from_millis(59_500);
+        assert_eq!(app.remaining_display_secs(), 59);
+        app.config.countdown_rounding = Rounding::Ceil;
+        assert_eq!(app.remaining_display_secs(), 60);
+
+        // A fresh phase: 24:59 vs 25:00
+        app.paused_duration = Duration::from_millis(1);
+        assert_eq!(app.remaining_clock(), "25:00");
+        app.config.countdown_rounding = Rounding::Floor;
+        assert_eq!(app.remaining_clock(), "24:59");
+
+        // The last fraction of a second: 00:00 vs 00:01
+        app.paused_duration = mins(25) - Duration::from_millis(200);
+        assert_eq!(app.remaining_clock(), "00:00");
+        app.config.countdown_rounding = Rounding::Ceil;
+        assert_eq!(app.remaining_clock(), "00:01");
+
+        // Whole seconds are the same either way
+        app.paused_duration = mins(24);
+        assert_eq!(app.remaining_display_secs(), 60);
+        app.config.countdown_rounding = Rounding::Floor;
+        assert_eq!(app.remaining_display_secs(), 60);
+    }
+
+    #[test]
+    fn large_overrun_advances_once_and_ends_the_phase_on_time() {
+        let mut app = test_app();
+        let started_at = Local::now() - chrono::Duration::hours(3);
+        app.session_started_at = Some(started_at);
+        // Three hours went by unnoticed: several phases' worth
+        app.paused_duration = Duration::from_secs(3 * 60 * 60);
+        app.next_phase();
+
+        assert_eq!(app.phase, Phase::ShortBreak);
+        assert_eq!(app.pomodoro_count, 1);
+        assert_eq!(app.history.len(), 1);
+        let record = &app.history[0];
+        assert_eq!(record.active_secs, 25 * 60);
+        let ended_after = (record.ended_at - started_at).num_seconds();
+        assert!((25 * 60..25 * 60 + 5).contains(&ended_after));
+    }
+
+    #[test]
+    fn inverted_gauge_shows_the_remaining_share() {
+        let mut app = test_app();
+        app.paused_duration = mins(10);
+        assert!((app.gauge_ratio() - 0.4).abs() < 1e-9);
+
+        app.gauge_inverted = true;
+        assert!((app.gauge_ratio() - 0.6).abs() < 1e-9);
+        app.paused_duration = mins(25);
+        assert_eq!(app.gauge_ratio(), 0.0);
+    }
+
+    #[test]
+    fn custom_break_leaves_the_pomodoro_count_alone() {
+        let mut app = test_app();
+        app.pomodoro_count = 2;
+        app.start_custom_break();
+        assert_eq!(app.phase, Phase::CustomBreak);
+        assert_eq!(app.get_target_duration(), mins(30));
+
+        app.toggle_timer();
+        app.paused_duration = mins(30);
+        app.running = false;
+        app.next_phase();
+
+        assert_eq!(app.phase, Phase::Focus);
+        assert_eq!(app.pomodoro_count, 2);
+        assert_eq!(app.history[0].phase, Phase::CustomBreak);
+    }
+
+    #[test]
+    fn restoring_ui_state_falls_back_to_timer_and_focus() {
+        let mut app = test_app();
+        app.restore_ui_state(UiState {
+            last_tab: Some(AppTab::Settings),
+            last_setting: Some(SettingSelection::LongBreakTime),
+            last_task: None,
+            tutorial_seen: true,
+        });
+        assert_eq!(app.current_tab, AppTab::Settings);
+        assert_eq!(app.selected_setting, SettingSelection::LongBreakTime);
+
+        app.restore_ui_state(UiState::default());
+        assert_eq!(app.current_tab, AppTab::Timer);
+        assert_eq!(app.selected_setting, SettingSelection::FocusTime);
+    }
+
+    #[test]
+    fn space_moves_through_ready_running_and_paused() {
+        let mut app = test_app();
+        assert_eq!(app.timer_state(), TimerState::Ready);
+
+        app.toggle_timer();
+        assert_eq!(app.timer_state(), TimerState::Running);
+        assert_eq!(app.paused_duration, Duration::ZERO);
+        assert!(app.session_started_at.is_some());
+
+        app.start_time = Instant::now() - Duration::from_secs(5);
+        app.toggle_timer();
+        assert_eq!(app.timer_state(), TimerState::Paused);
+        assert!(app.paused_duration >= Duration::from_secs(5));
+
+        let paused = app.paused_duration;
+        app.toggle_timer();
+        assert_eq!(app.timer_state(), TimerState::Running);
+        assert_eq!(app.paused_duration, paused);
+        assert!(app.get_elapsed() >= paused);
+    }
+
+    #[test]
+    fn minute_boundary_is_crossed_only_when_the_minute_drops() {
+        assert!(!minute_boundary_crossed(None, 24));
+        assert!(!minute_boundary_crossed(Some(25), 25));
+        assert!(minute_boundary_crossed(Some(25), 24));
+        // Reset or a new phase: the value jumps up, no tick
+        assert!(!minute_boundary_crossed(Some(3), 25));
+    }
+
+    #[test]
+    fn minute_tick_only_during_a_running_focus() {
+        let mut app = test_app();
+        app.config.minute_tick = true;
+        app.toggle_timer();
+        assert!(app.minute_tick_enabled());
+
+        app.config.mute = true;
+        assert!(!app.minute_tick_enabled());
+        app.config.mute = false;
+
+        app.toggle_timer(); // Paused
+        assert!(!app.minute_tick_enabled());
+
+        app.phase = Phase::ShortBreak;
+        app.reset_timer();
+        app.toggle_timer();
+        assert!(!app.minute_tick_enabled());
+    }
+
+    #[test]
+    fn skipping_the_long_break_goes_to_focus_and_keeps_the_count() {
+        let mut app = test_app();
+        app.config.confirm_long_break = true;
+        app.pomodoro_count = 3;
+        app.session_started_at = Some(Local::now());
+        app.paused_duration = mins(25);
+        app.next_phase();
+        assert_eq!(app.phase, Phase::LongBreak);
+        assert!(app.long_break_prompt);
+
+        app.skip_long_break();
+        assert_eq!(app.phase, Phase::Focus);
+        assert_eq!(app.pomodoro_count, 4);
+        assert!(!app.long_break_prompt);
+        assert_eq!(app.timer_state(), TimerState::Ready);
+        // Logged as a skipped break
+        let skipped = app.history.last().unwrap();
+        assert_eq!(skipped.phase, Phase::LongBreak);
+        assert_eq!(skipped.active_secs, 0);
+    }
+
+    #[test]
+    fn skipping_focus_below_the_threshold_is_not_counted() {
+        let mut app = test_app();
+        app.config.min_focus_fraction = 0.5;
+        app.session_started_at = Some(Local::now());
+        app.paused_duration = mins(10);
+        assert!(!app.focus_counts());
+        app.next_phase();
+
+        assert_eq!(app.pomodoro_count, 0);
+        assert_eq!(app.phase, Phase::ShortBreak);
+        assert!(!app.history[0].counted);
+        assert!(!app.history[0].completed);
+    }
+
+    #[test]
+    fn skipping_focus_above_the_threshold_is_counted() {
+        let mut app = test_app();
+        app.config.min_focus_fraction = 0.5;
+        app.session_started_at = Some(Local::now());
+        app.paused_duration = mins(15);
+        assert!(app.focus_counts());
+        app.next_phase();
+
+        assert_eq!(app.pomodoro_count, 1);
+        assert!(app.history[0].counted);
+        assert!(!app.history[0].completed);
+    }
+
+    #[test]
+    fn finishing_a_round_shows_stats_then_returns() {
+        let mut app = test_app();
+        app.config.round_stats_secs = 5;
+        app.phase = Phase::LongBreak;
+        app.session_started_at = Some(Local::now());
+        app.paused_duration = mins(15);
+        app.next_phase();
+
+        assert_eq!(app.current_tab, AppTab::Stats);
+        app.check_stats_return();
+        assert_eq!(app.current_tab, AppTab::Stats);
+
+        app.stats_return_at = Some(Instant::now());
+        app.check_stats_return();
+        assert_eq!(app.current_tab, AppTab::Timer);
+        assert_eq!(app.stats_return_at, None);
+    }
+
+    #[test]
+    fn short_break_ending_is_not_a_round() {
+        let mut app = test_app();
+        app.config.round_stats_secs = 5;
+        app.phase = Phase::ShortBreak;
+        app.next_phase();
+        assert_eq!(app.current_tab, AppTab::Timer);
+        assert_eq!(app.stats_return_at, None);
+    }
+
+    #[test]
+    fn plain_countdown_finishes_without_a_phase_transition() {
+        let mut app = test_app();
+        app.plain = true;
+        assert_eq!(app.get_target_duration(), mins(10));
+        app.toggle_timer();
+        app.paused_duration = mins(10);
+        app.reset_at = Instant::now() - AUTO_COMPLETE_GUARD;
+        assert!(app.auto_complete_due());
+
+        app.finish_countdown();
+        assert_eq!(app.phase, Phase::Focus);
+        assert_eq!(app.pomodoro_count, 0);
+        assert!(app.history.is_empty());
+        assert_eq!(app.timer_state(), TimerState::Ready);
+        assert_eq!(app.get_remaining(), mins(10));
+    }
+
+    /// A short break skipped just now, adding its whole length to the rest debt.
+    fn skipped_break() -> SessionRecord {
+        let now = Local::now();
+        SessionRecord::new(Phase::ShortBreak, now, now, mins(5), Duration::ZERO, false)
+    }
+
+    #[test]
+    fn rest_debt_nudges_once_when_crossing_the_threshold() {
+        let mut app = test_app();
+        app.config.rest_debt_mins = 10;
+        app.history.push(skipped_break());
+        assert!(!app.check_rest_debt());
+        assert_eq!(app.rest_debt_warned, None);
+
+        app.history.push(skipped_break());
+        assert!(app.check_rest_debt());
+        assert_eq!(app.rest_debt_warned, Some(Local::now().date_naive()));
+
+        app.history.push(skipped_break());
+        assert!(!app.check_rest_debt());
+    }
+
+    #[test]
+    fn rest_debt_nudge_is_off_at_zero() {
+        let mut app = test_app();
+        app.history = vec![skipped_break(); 10];
+        assert!(!app.check_rest_debt());
+    }
+
+    #[test]
+    fn fast_forward_is_a_no_op_during_focus() {
+        let mut app = test_app();
+        app.toggle_timer();
+        app.fast_forward_break();
+        assert_eq!(app.fast_forward, Duration::ZERO);
+        assert_eq!(app.phase, Phase::Focus);
+    }
+
+    #[test]
+    fn fast_forward_winds_a_break_down_and_ends_it() {
+        let mut app = test_app();
+        app.phase = Phase::ShortBreak;
+        app.paused_duration = mins(4);
+        app.fast_forward_break();
+        assert_eq!(app.get_remaining(), Duration::from_secs(30));
+        app.fast_forward_break();
+
+        assert_eq!(app.phase, Phase::Focus);
+        // Fast-forwarded time doesn't count as rest
+        assert_eq!(app.history[0].active_secs, 4 * 60);
+    }
+
+    /// Runs the current phase to the end and moves on.
+    fn finish_phase(app: &mut App) {
+        app.session_started_at.get_or_insert_with(Local::now);
+        app.running = false;
+        app.paused_duration = app.get_target_duration();
+        app.next_phase();
+    }
+
+    #[test]
+    fn skipping_short_breaks_still_takes_the_long_break() {
+        let mut app = test_app();
+        app.config.skip_short_breaks = true;
+        for _ in 0..3 {
+            finish_phase(&mut app);
+            assert_eq!(app.phase, Phase::Focus);
+        }
+        finish_phase(&mut app);
+        assert_eq!(app.phase, Phase::LongBreak);
+        assert_eq!(app.pomodoro_count, 4);
+        finish_phase(&mut app);
+
+        let phases: Vec<Phase> = app.history.iter().map(|record| record.phase).collect();
+        assert_eq!(
+            phases,
+            [
+                Phase::Focus,
+                Phase::Focus,
+                Phase::Focus,
+                Phase::Focus,
+                Phase::LongBreak
+            ]
+        );
+    }
+
+    #[test]
+    fn remaining_time_sets_the_paused_duration() {
+        let mut app = test_app();
+        app.set_remaining(Duration::from_secs(12 * 60 + 30))
+            .unwrap();
+        assert_eq!(app.paused_duration, Duration::from_secs(12 * 60 + 30));
+        assert_eq!(app.timer_state(), TimerState::Paused);
+
+        app.set_remaining(mins(25)).unwrap();
+        assert_eq!(app.paused_duration, Duration::ZERO);
+    }
+
+    #[test]
+    fn remaining_time_beyond_the_phase_is_rejected() {
+        let mut app = test_app();
+        app.paused_duration = mins(3);
+        assert!(
+            app.set_remaining(mins(25) + Duration::from_secs(1))
+                .is_err()
+        );
+        assert_eq!(app.paused_duration, mins(3));
+    }
+
+    #[test]
+    fn adjusting_at_a_limit_reports_the_clamp() {
+        let mut app = test_app();
+        app.cfg_focus = 120;
+        app.toggle_timer();
+        app.adjust_setting(5);
+        assert_eq!(app.cfg_focus, 120);
+        assert_eq!(app.clamp_hint(), Some("max"));
+        assert!(app.running, "a clamped press leaves the timer alone");
+
+        app.adjust_setting(-5);
+        assert_eq!(app.cfg_focus, 115);
+        assert_eq!(app.clamp_hint(), None);
+
+        app.selected_setting = SettingSelection::ShortBreakTime;
+        app.cfg_short = 1;
+        app.adjust_setting(-5);
+        assert_eq!(app.cfg_short, 1);
+        assert_eq!(app.clamp_hint(), Some("min"));
+    }
+
+    #[test]
+    fn deep_work_chains_one_round_into_the_long_break() {
+        let mut app = test_app();
+        app.toggle_deep_work();
+        for count in 1..=3 {
+            finish_phase(&mut app);
+            assert_eq!(app.phase, Phase::Focus);
+            assert_eq!(app.pomodoro_count, count);
+            assert!(app.running, "the next focus session starts on its own");
+        }
+        finish_phase(&mut app);
+        assert_eq!(app.phase, Phase::LongBreak);
+        assert!(!app.running);
+        assert!(!app.deep_work);
+
+        // The next round has its short breaks again
+        finish_phase(&mut app);
+        finish_phase(&mut app);
+        assert_eq!(app.phase, Phase::ShortBreak);
+        assert!(
+            app.history[..4]
+                .iter()
+                .all(|record| record.phase == Phase::Focus)
+        );
+    }
+
+    #[test]
+    fn idle_quit_waits_for_the_full_limit() {
+        assert!(!idle_quit_due(Duration::from_secs(24 * 60 * 60), 0));
+        assert!(!idle_quit_due(mins(10) - Duration::from_millis(1), 10));
+        assert!(idle_quit_due(mins(10), 10));
+        assert!(idle_quit_due(mins(11), 10));
+    }
+
+    #[test]
+    fn adjusting_respects_custom_bounds() {
+        let mut app = test_app();
+        app.config.bounds.focus = crate::config::Bounds { min: 20, max: 180 };
+        app.cfg_focus = 170;
+        app.adjust_setting(5);
+        app.adjust_setting(5);
+        assert_eq!(app.cfg_focus, 180);
+        app.adjust_setting(5);
+        assert_eq!(app.cfg_focus, 180);
+        assert_eq!(app.clamp_hint(), Some("max"));
+
+        app.cfg_focus = 22;
+        app.adjust_setting(-5);
+        assert_eq!(app.cfg_focus, 20);
+    }
+
+    #[test]
+    fn out_of_bounds_values_only_move_back_toward_the_bounds() {
+        let mut app = test_app();
+        app.cfg_focus = 200; // Over the default maximum of 120
+        app.adjust_setting(5);
+        assert_eq!(app.cfg_focus, 200);
+        assert_eq!(app.clamp_hint(), Some("max"));
+        app.adjust_setting(-5);
+        assert_eq!(app.cfg_focus, 195);
+    }
+
+    #[test]
+    fn reset_restores_exactly_the_default_values() {
+        let mut app = test_app();
+        app.config = toml::from_str("focus_mins = 50\nplain_timer = true\nmute = true").unwrap();
+        app.reload_config(app.config.clone());
+        app.plain = true;
+        app.deep_work = true;
+        app.gauge_inverted = true;
+        app.cfg_plain = 45;
+        app.selected_setting = SettingSelection::LongBreakTime;
+        app.toggle_timer();
+        app.paused_duration = mins(40);
+
+        app.reset_to_defaults(false);
+        let defaults = test_app();
+        assert_eq!(
+            toml::to_string(&app.config).unwrap(),
+            toml::to_string(&defaults.config).unwrap()
+        );
+        assert_eq!(
+            (app.cfg_focus, app.cfg_short, app.cfg_long, app.cfg_plain),
+            (
+                defaults.cfg_focus,
+                defaults.cfg_short,
+                defaults.cfg_long,
+                defaults.cfg_plain
+            )
+        );
+        assert_eq!(app.long_break_interval, defaults.long_break_interval);
+        assert_eq!(app.plain, defaults.plain);
+        assert_eq!(app.deep_work, defaults.deep_work);
+        assert_eq!(app.gauge_inverted, defaults.gauge_inverted);
+        assert_eq!(app.selected_setting, defaults.selected_setting);
+
+        // A 50 minute focus that ran 40 must not end (and count) on the next frame
+        assert_eq!(app.timer_state(), TimerState::Ready);
+        assert_eq!(app.get_remaining(), mins(25));
+        assert!(!app.auto_complete_due());
+    }
+
+    #[test]
+    fn quit_needs_a_second_press_within_the_grace_window() {
+        let mut app = test_app();
+        assert!(app.confirm_quit(), "no grace window: the first press quits");
+
+        app.config.quit_grace_ms = 1500;
+        assert!(!app.confirm_quit());
+        assert_eq!(app.flash_message(), Some("Press q again to quit"));
+        assert!(app.confirm_quit());
+
+        // Too slow: the late press starts over
+        app.quit_pressed_at = Some(Instant::now() - Duration::from_millis(1600));
+        assert!(!app.confirm_quit());
+        assert!(app.confirm_quit());
+    }
+
+    #[test]
+    fn phase_synced_past_its_plan_is_logged_as_over() {
+        let mut app = test_app();
+        // The peer's focus sessions are longer: ours is already 5 minutes over
+        app.apply_sync(&SyncMessage {
+            phase: Phase::Focus,
+            pomodoro_count: 0,
+            running: true,
+            elapsed_ms: 30 * 60 * 1000,
+            sent_at: Local::now(),
+        });
+        app.next_phase();
+
+        let record = &app.history[0];
+        assert_eq!(record.planned_secs, 25 * 60);
+        assert!((30 * 60..30 * 60 + 5).contains(&record.active_secs));
+        assert_eq!(record.adherence(), 120);
+    }
+
+    #[test]
+    fn auto_complete_holds_off_right_after_a_phase_change() {
+        let mut app = test_app();
+        app.toggle_timer();
+        app.paused_duration = mins(25); // Stale: the new phase is already used up
+        app.reset_at = Instant::now();
+        assert!(!app.auto_complete_due());
+
+        app.reset_at = Instant::now() - AUTO_COMPLETE_GUARD;
+        assert!(app.auto_complete_due());
+
+        // A manual switch restarts the guard
+        app.phase = Phase::ShortBreak;
+        app.reset_timer();
+        app.toggle_timer();
+        app.paused_duration = mins(5);
+        assert!(!app.auto_complete_due());
+    }
+
+    /// Local time on `date`, e.g. `local_time(today, 10, 30)`.
+    fn local_time(date: NaiveDate, hour: u32, min: u32) -> DateTime<Local> {
+        date.and_hms_opt(hour, min, 0)
+            .unwrap()
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap()
+    }
+
+    fn reminder_app() -> App {
+        let mut app = test_app();
+        app.config.first_focus_reminder = true;
+        app.config.first_focus_by = chrono::NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+        app
+    }
+
+    #[test]
+    fn first_focus_reminder_fires_once_a_day_after_its_time() {
+        let mut app = reminder_app();
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        assert!(!app.check_first_focus(local_time(today, 9, 59)));
+        assert!(app.check_first_focus(local_time(today, 10, 0)));
+        assert!(!app.check_first_focus(local_time(today, 11, 0)));
+
+        let tomorrow = today.succ_opt().unwrap();
+        assert!(app.check_first_focus(local_time(tomorrow, 10, 30)));
+    }
+
+    #[test]
+    fn first_focus_reminder_is_skipped_once_focus_started() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+
+        // Running (or paused) in a focus session started this morning
+        let mut app = reminder_app();
+        app.session_started_at = Some(local_time(today, 8, 45));
+        assert!(!app.check_first_focus(local_time(today, 10, 5)));
+        assert_eq!(app.first_focus_reminded, Some(today));
+
+        // A focus session already logged today
+        let mut app = reminder_app();
+        let ended_at = local_time(today, 9, 25);
+        app.history.push(SessionRecord::new(
+            Phase::Focus,
+            ended_at - chrono::Duration::minutes(25),
+            ended_at,
+            mins(25),
+            mins(25),
+            true,
+        ));
+        assert!(!app.check_first_focus(local_time(today, 10, 5)));
+
+        // Yesterday's session doesn't count for today
+        assert!(app.check_first_focus(local_time(today.succ_opt().unwrap(), 10, 5)));
+    }
+
+    #[test]
+    fn daily_goal_is_celebrated_once_a_day() {
+        let mut app = test_app();
+        app.config.daily_goal = 2;
+        app.config.mute = true;
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        let tomorrow = today.succ_opt().unwrap();
+        let pomodoro = |ended_at: DateTime<Local>| {
+            SessionRecord::new(
+                Phase::Focus,
+                ended_at - chrono::Duration::minutes(25),
+                ended_at,
+                mins(25),
+                mins(25),
+                true,
+            )
+        };
+
+        app.history.push(pomodoro(local_time(today, 9, 25)));
+        assert!(!app.check_daily_goal(local_time(today, 9, 25)));
+        app.history.push(pomodoro(local_time(today, 10, 0)));
+        assert!(app.check_daily_goal(local_time(today, 10, 0)));
+        app.history.push(pomodoro(local_time(today, 11, 0)));
+        assert!(!app.check_daily_goal(local_time(today, 11, 0)));
+
+        // A new day starts from zero: yesterday's pomodoros don't count
+        assert!(!app.check_daily_goal(local_time(tomorrow, 9, 25)));
+        app.history.push(pomodoro(local_time(tomorrow, 9, 25)));
+        app.history.push(pomodoro(local_time(tomorrow, 10, 0)));
+        assert!(app.check_daily_goal(local_time(tomorrow, 10, 0)));
+        assert_eq!(app.goal_celebrated, Some(tomorrow));
+    }
+
+    /// A focus session that has been running for five minutes.
+    fn running_focus() -> App {
+        let mut app = test_app();
+        app.toggle_timer();
+        app.start_time -= mins(5);
+        app
+    }
+
+    #[test]
+    fn interrupt_records_one_interruption_and_pauses() {
+        let mut app = running_focus();
+        app.interrupt();
+        assert!(!app.running);
+        assert!(app.interrupted);
+        assert_eq!(app.timer_state(), TimerState::Paused);
+        assert_eq!(app.interruptions.len(), 1);
+
+        // Already paused: a second press records nothing more
+        app.interrupt();
+        assert_eq!(app.interruptions.len(), 1);
+
+        app.tag_interruption(Some(0));
+        assert_eq!(app.interruptions[0].reason.as_deref(), Some("Meeting"));
+        assert!(app.history.is_empty());
+    }
+
+    #[test]
+    fn starting_over_logs_an_interrupted_phase() {
+        // [R]
+        let mut app = running_focus();
+        app.interrupt();
+        app.reset_timer();
+        assert_eq!(app.phase, Phase::Focus);
+        assert!(app.interruptions.is_empty());
+        assert_eq!(app.history.len(), 1);
+        assert_eq!(app.history[0].phase, Phase::Focus);
+        assert_eq!(app.history[0].interruptions.len(), 1);
+        assert!(!app.history[0].counted);
+
+        // [2]: logged as the focus it was, not the break it switched to
+        let mut app = running_focus();
+        app.interrupt();
+        app.set_phase(Phase::ShortBreak);
+        assert_eq!(app.history.len(), 1);
+        assert_eq!(app.history[0].phase, Phase::Focus);
+
+        // A duration change on the Settings tab keeps the planned length
+        let mut app = running_focus();
+        app.interrupt();
+        app.adjust_setting(5);
+        assert_eq!(app.history.len(), 1);
+        assert_eq!(app.history[0].planned_secs, 25 * 60);
+
+        // Without an interruption, starting over logs nothing
+        let mut app = running_focus();
+        app.reset_timer();
+        assert!(app.history.is_empty());
+    }
+
+    #[test]
+    fn last_task_is_offered_on_the_next_launch() {
+        let mut app = test_app();
+        app.current_task = Some("Refactor parser".to_string());
+        let state = app.ui_state();
+
+        // Declined: no task
+        let mut app = test_app();
+        app.restore_ui_state(state.clone());
+        app.offer_last_task();
+        assert!(app.resume_task_prompt);
+        app.resume_last_task(false);
+        assert!(!app.resume_task_prompt);
+        assert_eq!(app.current_task, None);
+
+        // Accepted: prefilled
+        let mut app = test_app();
+        app.restore_ui_state(state);
+        app.offer_last_task();
+        app.resume_last_task(true);
+        assert_eq!(app.current_task.as_deref(), Some("Refactor parser"));
+
+        // No previous task, or a `--task` given: no prompt
+        let mut app = test_app();
+        app.restore_ui_state(UiState::default());
+        app.offer_last_task();
+        assert!(!app.resume_task_prompt);
+
+        let mut app = test_app();
+        app.restore_ui_state(UiState {
+            last_task: Some("Refactor parser".to_string()),
+            ..UiState::default()
+        });
+        app.current_task = Some("Docs".to_string());
+        app.offer_last_task();
+        assert!(!app.resume_task_prompt);
+    }
+
+    #[test]
+    fn recent_tasks_keep_the_latest_first_and_cycle_in_order() {
+        let mut app = test_app();
+        for task in ["A", "B", "C", "A", "D", "E", "F"] {
+            app.remember_task(task.to_string());
+        }
+        // "A" moved up instead of repeating; the oldest ("B") fell off
+        assert_eq!(app.recent_tasks, ["F", "E", "D", "A", "C"]);
+
+        app.cycle_task();
+        assert_eq!(app.current_task.as_deref(), Some("F"));
+        app.cycle_task();
+        assert_eq!(app.current_task.as_deref(), Some("E"));
+        app.current_task = Some("C".to_string());
+        app.cycle_task();
+        assert_eq!(app.current_task.as_deref(), Some("F"));
+    }
+
+    #[test]
+    fn break_quote_stays_the_same_within_a_break() {
+        let mut app = test_app();
+        app.config.break_quotes = true;
+        app.config.quotes = ["Rest.", "Stretch.", "Breathe."].map(String::from).to_vec();
+        assert_eq!(app.break_quote, None);
+
+        app.set_phase(Phase::ShortBreak);
+        let quote = app.break_quote.clone();
+        assert!(quote.is_some());
+        app.toggle_timer();
+        app.reset_timer();
+        assert_eq!(app.break_quote, quote);
+
+        // The next break moves on to another quote
+        app.next_phase();
+        assert_eq!(app.break_quote, None);
+        app.set_phase(Phase::ShortBreak);
+        assert!(app.break_quote.is_some());
+        assert_ne!(app.break_quote, quote);
+    }
+
+    #[test]
+    fn tutorial_shows_until_closed_once() {
+        let mut app = test_app();
+        app.restore_ui_state(UiState::default());
+        assert_eq!(app.tutorial_step, Some(0));
+
+        app.next_tutorial_step();
+        app.finish_tutorial();
+        assert_eq!(app.tutorial_step, None);
+        let state = app.ui_state();
+        assert!(state.tutorial_seen);
+
+        // Next launch
+        let mut app = test_app();
+        app.restore_ui_state(state);
+        assert_eq!(app.tutorial_step, None);
+        assert!(app.ui_state().tutorial_seen);
+    }
+
+    #[test]
+    fn tutorial_is_not_replayed_for_a_new_profile() {
+        let mut app = test_app();
+        app.restore_ui_state(UiState::default());
+        app.finish_tutorial();
+        let state = app.ui_state();
+
+        // A profile without its own file yet loads the defaults
+        let config = Config {
+            profile: Some("Work".to_string()),
+            ..Config::default()
+        };
+        let mut app = App::new(config, Vec::new());
+        app.restore_ui_state(state);
+        assert_eq!(app.tutorial_step, None);
+    }
+
+    #[test]
+    fn reload_applies_plain_timer_and_gauge_direction() {
+        let mut app = test_app();
+        app.toggle_timer();
+        app.reload_config(toml::from_str("plain_timer = true\ngauge_inverted = true").unwrap());
+        assert!(app.plain);
+        assert!(app.gauge_inverted);
+        assert_eq!(app.timer_state(), TimerState::Ready);
+
+        app.reload_config(Config::default());
+        assert!(!app.plain);
+        assert!(!app.gauge_inverted);
+
+        // `--plain` wins over the file
+        app.plain_forced = true;
+        app.plain = true;
+        app.toggle_timer();
+        app.reload_config(Config::default());
+        assert!(app.plain);
+        assert_eq!(app.timer_state(), TimerState::Running);
+    }
+
+    #[test]
+    fn reset_keeps_the_plain_flag_from_the_command_line() {
+        let mut app = test_app();
+        app.plain_forced = true;
+        app.plain = true;
+        app.reset_to_defaults(false);
+        assert!(app.plain);
+    }
+
+    #[test]
+    fn switching_away_from_a_break_logs_it_like_next_phase() {
+        // [N], [1] and [B] all leave the same rest debt behind
+        for switch in [
+            App::next_phase as fn(&mut App),
+            |app: &mut App| app.set_phase(Phase::Focus),
+            App::start_custom_break,
+        ] {
+            let mut app = test_app();
+            app.set_phase(Phase::ShortBreak);
+            switch(&mut app);
+            assert_eq!(app.history.len(), 1);
+            assert_eq!(app.history[0].phase, Phase::ShortBreak);
+            assert_eq!(stats::rest_debt(&app.history), mins(5));
+        }
+
+        // Restarting the break with [2] isn't skipping it
+        let mut app = test_app();
+        app.set_phase(Phase::ShortBreak);
+        app.set_phase(Phase::ShortBreak);
+        assert!(app.history.is_empty());
+
+        // A focus session cut short with [2] is logged, but doesn't count
+        let mut app = running_focus();
+        app.set_phase(Phase::ShortBreak);
+        assert_eq!(app.history.len(), 1);
+        assert!(!app.history[0].counted);
+        assert_eq!(app.pomodoro_count, 0);
+    }
 }
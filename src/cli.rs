@@ -0,0 +1,119 @@
+use std::{io, time::Duration};
+
+// --- Command Line Arguments ---
+
+const USAGE: &str = "Usage: pomodoro-tui [OPTIONS]
+
+Options:
+  --profile <NAME>    Use the config profile NAME (e.g. Work, Study)
+  --task <NAME>       Label the focus sessions with the task you're working on
+  --plain             Run a single countdown (see plain_timer_mins) instead of pomodoros
+  --remaining <TIME>  Start with TIME (MM:SS or seconds) left in the first phase
+  --start-running     Start the timer right away
+  --listen <ADDR>     Pair mode: share phases with a peer connecting to ADDR (e.g. 0.0.0.0:7878;
+                      unauthenticated, so only on a trusted network)
+  --connect <ADDR>    Pair mode: follow the peer listening on ADDR
+  --snapshots         Enable [P] to save the screen as text/SVG files
+  --oneshot           Print the running timer's status line (see status_format) and exit
+  --metrics           Write stats in Prometheus textfile format (see metrics_path) and exit
+  -h, --help          Print this help";
+
+#[derive(Debug, Default)]
+pub struct Args {
+    pub profile: Option<String>,
+    pub task: Option<String>,
+    pub plain: bool,
+    pub remaining: Option<Duration>,
+    pub start_running: bool,
+    pub listen: Option<String>,
+    pub connect: Option<String>,
+    pub snapshots: bool,
+    pub oneshot: bool,
+    pub metrics: bool,
+    pub help: bool,
+}
+
+impl Args {
+    pub fn parse() -> io::Result<Self> {
+        Self::parse_from(std::env::args().skip(1))
+    }
+
+    fn parse_from(args: impl IntoIterator<Item = String>) -> io::Result<Self> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--profile" => parsed.profile = Some(value(&arg, args.next())?),
+                "--task" => parsed.task = Some(value(&arg, args.next())?),
+                "--plain" => parsed.plain = true,
+                "--remaining" => parsed.remaining = Some(parse_time(&value(&arg, args.next())?)?),
+                "--start-running" => parsed.start_running = true,
+                "--listen" => parsed.listen = Some(value(&arg, args.next())?),
+                "--connect" => parsed.connect = Some(value(&arg, args.next())?),
+                "--snapshots" => parsed.snapshots = true,
+                "--oneshot" => parsed.oneshot = true,
+                "--metrics" => parsed.metrics = true,
+                "-h" | "--help" => parsed.help = true,
+                _ => return Err(invalid(format!("unknown argument '{}'", arg))),
+            }
+        }
+        if parsed.listen.is_some() && parsed.connect.is_some() {
+            return Err(invalid(
+                "--listen and --connect can't be combined".to_string(),
+            ));
+        }
+        Ok(parsed)
+    }
+
+    pub fn usage() -> &'static str {
+        USAGE
+    }
+}
+
+fn value(flag: &str, value: Option<String>) -> io::Result<String> {
+    value
+        .filter(|value| !value.starts_with("--"))
+        .ok_or_else(|| invalid(format!("{} requires a value", flag)))
+}
+
+/// "MM:SS" or plain seconds.
+fn parse_time(value: &str) -> io::Result<Duration> {
+    let secs = match value.split_once(':') {
+        Some((mins, secs)) => mins
+            .parse::<u64>()
+            .ok()
+            .zip(secs.parse::<u64>().ok().filter(|secs| *secs < 60))
+            .map(|(mins, secs)| mins * 60 + secs),
+        None => value.parse().ok(),
+    };
+    secs.map(Duration::from_secs)
+        .ok_or_else(|| invalid(format!("invalid time '{}' (use MM:SS or seconds)", value)))
+}
+
+fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, format!("{}\n\n{}", msg, USAGE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_parses_as_minutes_and_seconds_or_plain_seconds() {
+        assert_eq!(parse_time("12:30").unwrap(), Duration::from_secs(750));
+        assert_eq!(parse_time("750").unwrap(), Duration::from_secs(750));
+        assert_eq!(parse_time("0:05").unwrap(), Duration::from_secs(5));
+        assert!(parse_time("12:60").is_err());
+        assert!(parse_time("12:").is_err());
+        assert!(parse_time("-5").is_err());
+    }
+
+    #[test]
+    fn remaining_flag_combines_with_start_running() {
+        let args = ["--remaining", "12:30", "--start-running"].map(String::from);
+        let parsed = Args::parse_from(args).unwrap();
+        assert_eq!(parsed.remaining, Some(Duration::from_secs(750)));
+        assert!(parsed.start_running);
+    }
+}
@@ -0,0 +1,30 @@
+use std::io;
+
+// --- Clipboard ---
+
+/// The clipboard handle lives for the rest of the run: on X11 and Wayland the
+/// copying app serves the contents itself, so dropping it would lose them
+/// (unless a clipboard manager picked them up).
+#[cfg(feature = "clipboard")]
+static CLIPBOARD: std::sync::Mutex<Option<arboard::Clipboard>> = std::sync::Mutex::new(None);
+
+/// Copies `text` to the system clipboard. Fails when there's no clipboard to
+/// talk to (e.g. headless, over SSH).
+#[cfg(feature = "clipboard")]
+pub fn copy(text: &str) -> io::Result<()> {
+    let mut clipboard = CLIPBOARD
+        .lock()
+        .map_err(|_| io::Error::other("clipboard lock poisoned"))?;
+    if clipboard.is_none() {
+        *clipboard = Some(arboard::Clipboard::new().map_err(io::Error::other)?);
+    }
+    match clipboard.as_mut() {
+        Some(clipboard) => clipboard.set_text(text).map_err(io::Error::other),
+        None => Ok(()),
+    }
+}
+
+#[cfg(not(feature = "clipboard"))]
+pub fn copy(_text: &str) -> io::Result<()> {
+    Err(io::Error::other("built without the `clipboard` feature"))
+}
@@ -0,0 +1,641 @@
+use chrono::{NaiveTime, Weekday};
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::app::Phase;
+
+// --- Persistent Configuration ---
+
+/// How the displayed countdown rounds partial seconds.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Rounding {
+    #[default]
+    Floor,
+    Ceil,
+}
+
+/// What the progress gauge label shows.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GaugeLabel {
+    #[default]
+    Percent,
+    Remaining,
+}
+
+/// Font for the big countdown digits.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimerFont {
+    /// Block digits (tui-big-text)
+    #[default]
+    BigText,
+    /// Bundled figlet fonts
+    Standard,
+    Small,
+    Big,
+    Slant,
+}
+
+/// How the Timer tab shows progress through the current round.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CycleDisplay {
+    /// "Pomodoros Completed: 2/4"
+    #[default]
+    Count,
+    /// Focus and break steps, e.g. "F✓ B✓ F✓ B✓ F⋯"
+    Combined,
+}
+
+/// An ad-hoc break outside the regular cycle, from the `[[custom_breaks]]` list.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CustomBreak {
+    pub name: String,
+    pub mins: u64,
+}
+
+/// Duration overrides for one weekday, from a `[weekdays.<day>]` table.
+/// Unset fields keep the base value.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DayOverride {
+    pub focus_mins: Option<u64>,
+    pub short_break_mins: Option<u64>,
+    pub long_break_mins: Option<u64>,
+    pub long_break_interval: Option<u8>,
+}
+
+/// Allowed range of one duration on the Settings tab, in minutes.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct Bounds {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl Bounds {
+    const fn new(min: u64, max: u64) -> Self {
+        Self { min, max }
+    }
+}
+
+/// Per-setting bounds, from the `[bounds]` table.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SettingBounds {
+    pub focus: Bounds,
+    pub short_break: Bounds,
+    pub long_break: Bounds,
+    pub plain_timer: Bounds,
+}
+
+impl Default for SettingBounds {
+    fn default() -> Self {
+        Self {
+            focus: Bounds::new(1, 120),
+            short_break: Bounds::new(1, 60),
+            long_break: Bounds::new(1, 60),
+            plain_timer: Bounds::new(1, 120),
+        }
+    }
+}
+
+/// Time of day when sound cues stay silent, from the `[quiet_hours]` table.
+/// The window may wrap past midnight, e.g. 22:00 to 07:00.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    /// True when `time` falls in the window (`start` inclusive, `end` exclusive).
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// Notification urgency (only honored by freedesktop notification servers).
+#[derive(Clone, Copy, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Urgency {
+    Low,
+    #[default]
+    Normal,
+    Critical,
+}
+
+/// What happens when a phase starts: the notification text (`{phase}` is
+/// replaced by the phase name), an optional sound file, and the urgency.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Alert {
+    pub summary: String,
+    pub body: String,
+    pub sound: Option<PathBuf>,
+    pub urgency: Urgency,
+}
+
+impl Default for Alert {
+    fn default() -> Self {
+        Self {
+            summary: "Phase Changed".to_string(),
+            body: "Starting {phase}".to_string(),
+            sound: None,
+            urgency: Urgency::Normal,
+        }
+    }
+}
+
+/// Per-phase alerts, from the `[alerts.<phase>]` tables.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Alerts {
+    pub focus: Alert,
+    pub short_break: Alert,
+    pub long_break: Alert,
+    pub custom_break: Alert,
+}
+
+impl Alerts {
+    /// The alert for the phase that is starting.
+    pub fn for_phase(&self, phase: Phase) -> &Alert {
+        match phase {
+            Phase::Focus => &self.focus,
+            Phase::ShortBreak => &self.short_break,
+            Phase::LongBreak => &self.long_break,
+            Phase::CustomBreak => &self.custom_break,
+        }
+    }
+}
+
+/// Colors for UI elements, from the `[theme]` table. Values accept ratatui color
+/// names ("yellow", "dark-gray"), hex ("#ffcc00") or indexed ("42") colors.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    pub selected_fg: Color,
+    pub selected_bg: Color,
+    pub status_ready: Color, // Timer tab status line, per timer state
+    pub status_running: Color,
+    pub status_paused: Color,
+    pub banner_fg: Color, // "Timer running" banner on the Settings tab
+    pub banner_bg: Color,
+    pub gauge_unfilled: Option<Color>, // Background of the gauge's unfilled portion (unset = as before)
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            selected_fg: Color::Yellow,
+            selected_bg: Color::DarkGray,
+            status_ready: Color::DarkGray,
+            status_running: Color::DarkGray,
+            status_paused: Color::DarkGray,
+            banner_fg: Color::Black,
+            banner_bg: Color::Yellow,
+            gauge_unfilled: None,
+        }
+    }
+}
+
+/// User configuration, loaded from `config.toml` in the platform config directory.
+/// Every field has a default, so a partial (or missing) file is always valid.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    // Durations (stored in minutes)
+    pub focus_mins: u64,
+    pub short_break_mins: u64,
+    pub long_break_mins: u64,
+    pub long_break_interval: u8,
+    pub custom_breaks: Vec<CustomBreak>,
+    pub weekdays: BTreeMap<String, DayOverride>, // Keyed by day name, e.g. "sat" or "Saturday"
+    pub bounds: SettingBounds, // Limits for adjusting durations on the Settings tab
+
+    // Daily Goal: pomodoros per day, paced over the working day (0 = off)
+    pub daily_goal: u32,
+    pub day_start: NaiveTime,
+    pub day_end: NaiveTime,
+    pub goal_sound: Option<PathBuf>, // Played when the goal is reached (terminal bell if unset)
+
+    // Morning nudge when no focus session was started by `first_focus_by`
+    pub first_focus_reminder: bool,
+    pub first_focus_by: NaiveTime,
+
+    // Strict Break: breaks take over the screen and cannot be skipped
+    pub strict_breaks: bool,
+    pub round_stats_secs: u64, // Show the Stats tab this long after a round completes (0 = off)
+    pub skip_short_breaks: bool, // Focus follows focus; only the long break is taken
+    pub confirm_long_break: bool, // Ask before starting a long break, so it can be skipped
+    pub min_focus_fraction: f64, // Share of a focus session that must pass for [N] to count it
+    pub show_attempts: bool,   // Stats tab also lists attempts, interrupted sessions included
+    pub interrupt_reasons: Vec<String>, // Quick tags offered after [I] (up to 9)
+    pub rest_debt_mins: u64,   // Nudge once a day when this much break time was skipped (0 = off)
+
+    // Plain Timer: a single named countdown instead of the pomodoro cycle
+    pub plain_timer: bool,
+    pub plain_timer_name: String,
+    pub plain_timer_mins: u64,
+
+    // Rendering: stick to plain ASCII symbols for minimal terminals
+    pub ascii: bool,
+    pub countdown_rounding: Rounding,
+    pub timer_font: TimerFont,
+    pub status_format: String, // `--oneshot` output, e.g. "{phase} {remaining}"
+    pub metrics_path: Option<PathBuf>, // `--metrics` output file (stdout if unset)
+    pub gauge_label: GaugeLabel,
+    pub gauge_icon: String,   // Prefix for the gauge label, empty to disable
+    pub gauge_inverted: bool, // Gauge starts full and drains as time runs out
+    pub cycle_display: CycleDisplay,
+    pub running_banner: bool, // Settings tab says so while the timer keeps running
+    pub show_clock: bool,     // Date and time at the right end of the footer
+    pub break_quotes: bool,   // A quote under the timer during breaks
+    pub quotes: Vec<String>,  // Empty = bundled set
+    pub quotes_file: Option<PathBuf>, // One quote per line, takes precedence over `quotes`
+    pub clock_24h: bool,
+
+    // Quit after the timer sits stopped with no input this long (0 = never)
+    pub auto_quit_idle_mins: u64,
+
+    // Require a second [Q] within this window to quit (0 = quit on the first press)
+    pub quit_grace_ms: u64,
+
+    // Notifications replace the previous one instead of stacking up
+    pub replace_notifications: bool,
+    pub alerts: Alerts,
+
+    // Sound
+    pub mute: bool,                      // Silences every sound cue
+    pub minute_tick: bool,               // Quiet bell each time a whole minute of focus passes
+    pub quiet_hours: Option<QuietHours>, // No sound cues during this window
+
+    // Focus Guardian: warn when switching to a blocklisted window during focus
+    pub focus_guardian: bool,
+    pub distraction_blocklist: Vec<String>,
+    pub guardian_poll_secs: u64,
+
+    pub theme: Theme,
+
+    // Profile this config was loaded for (from `--profile`), not stored in the file
+    #[serde(skip)]
+    pub profile: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            focus_mins: 25,
+            short_break_mins: 5,
+            long_break_mins: 15,
+            long_break_interval: 4,
+            custom_breaks: vec![CustomBreak {
+                name: "Lunch Break".to_string(),
+                mins: 30,
+            }],
+            weekdays: BTreeMap::new(),
+            bounds: SettingBounds::default(),
+            daily_goal: 0,
+            day_start: NaiveTime::from_hms_opt(9, 0, 0).unwrap_or_default(),
+            day_end: NaiveTime::from_hms_opt(17, 0, 0).unwrap_or_default(),
+            goal_sound: None,
+            first_focus_reminder: false,
+            first_focus_by: NaiveTime::from_hms_opt(10, 0, 0).unwrap_or_default(),
+            strict_breaks: false,
+            round_stats_secs: 0,
+            skip_short_breaks: false,
+            confirm_long_break: false,
+            min_focus_fraction: 0.0,
+            show_attempts: false,
+            rest_debt_mins: 0,
+            interrupt_reasons: ["Meeting", "Call", "Colleague", "Urgent Task"]
+                .map(String::from)
+                .to_vec(),
+            plain_timer: false,
+            plain_timer_name: "Countdown".to_string(),
+            plain_timer_mins: 10,
+            ascii: false,
+            countdown_rounding: Rounding::Floor,
+            timer_font: TimerFont::BigText,
+            status_format: "{phase} {remaining}".to_string(),
+            metrics_path: None,
+            gauge_label: GaugeLabel::Percent,
+            gauge_icon: String::new(),
+            gauge_inverted: false,
+            cycle_display: CycleDisplay::Count,
+            running_banner: true,
+            show_clock: false,
+            break_quotes: false,
+            quotes: Vec::new(),
+            quotes_file: None,
+            clock_24h: true,
+            auto_quit_idle_mins: 0,
+            quit_grace_ms: 0,
+            replace_notifications: true,
+            alerts: Alerts::default(),
+            mute: false,
+            minute_tick: false,
+            quiet_hours: None,
+            focus_guardian: false,
+            distraction_blocklist: Vec::new(),
+            guardian_poll_secs: 5,
+            theme: Theme::default(),
+            profile: None,
+        }
+    }
+}
+
+impl Config {
+    /// `config.toml` for the default profile, `profiles/<name>.toml` otherwise.
+    pub fn path(profile: Option<&str>) -> Option<PathBuf> {
+        // Tests never read or write the user's config
+        if cfg!(test) {
+            return None;
+        }
+        let dir = dirs::config_dir()?.join("pomodoro-tui");
+        Some(match profile {
+            Some(name) => dir.join("profiles").join(format!("{}.toml", name)),
+            None => dir.join("config.toml"),
+        })
+    }
+
+    /// Loads the config file, falling back to defaults when it doesn't exist yet.
+    pub fn load(profile: Option<&str>) -> io::Result<Self> {
+        if let Some(name) = profile {
+            validate_profile_name(name)?;
+        }
+
+        let mut config = match Self::path(profile) {
+            Some(path) => Self::read(&path)?,
+            None => Self::default(),
+        };
+        config.profile = profile.map(str::to_string);
+        Ok(config)
+    }
+
+    fn read(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+
+        let config: Self = toml::from_str(&contents).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), err),
+            )
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Writes the config back to disk, creating the config directory if needed.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = Self::path(self.profile.as_deref()) else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let contents = toml::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, contents)
+    }
+
+    /// True when sound cues are off at `time`: muted, or during quiet hours.
+    pub fn silenced(&self, time: NaiveTime) -> bool {
+        self.mute || self.quiet_hours.is_some_and(|quiet| quiet.contains(time))
+    }
+
+    /// The config with the `[weekdays]` override for `day` applied, if there is one.
+    pub fn for_weekday(&self, day: Weekday) -> Self {
+        let mut config = self.clone();
+        let Some(day_override) = self
+            .weekdays
+            .iter()
+            .find(|(name, _)| name.parse::<Weekday>() == Ok(day))
+            .map(|(_, day_override)| day_override)
+        else {
+            return config;
+        };
+
+        if let Some(mins) = day_override.focus_mins {
+            config.focus_mins = mins;
+        }
+        if let Some(mins) = day_override.short_break_mins {
+            config.short_break_mins = mins;
+        }
+        if let Some(mins) = day_override.long_break_mins {
+            config.long_break_mins = mins;
+        }
+        if let Some(interval) = day_override.long_break_interval {
+            config.long_break_interval = interval;
+        }
+        config
+    }
+
+    fn validate(&self) -> io::Result<()> {
+        let invalid = |msg: &str| Err(io::Error::new(io::ErrorKind::InvalidData, msg.to_string()));
+
+        if self.focus_mins == 0
+            || self.short_break_mins == 0
+            || self.long_break_mins == 0
+            || self.plain_timer_mins == 0
+        {
+            return invalid("durations must be at least 1 minute");
+        }
+        if self.custom_breaks.iter().any(|custom| custom.mins == 0) {
+            return invalid("custom break durations must be at least 1 minute");
+        }
+        if self.long_break_interval == 0 {
+            return invalid("long_break_interval must be at least 1");
+        }
+        if self.day_end <= self.day_start {
+            return invalid("day_end must be later than day_start");
+        }
+        if !(0.0..=1.0).contains(&self.min_focus_fraction) {
+            return invalid("min_focus_fraction must be between 0.0 and 1.0");
+        }
+        if self.guardian_poll_secs == 0 {
+            return invalid("guardian_poll_secs must be at least 1");
+        }
+
+        for (name, day_override) in &self.weekdays {
+            if name.parse::<Weekday>().is_err() {
+                return invalid(&format!("weekdays.{}: not a weekday name", name));
+            }
+            let mins = [
+                day_override.focus_mins,
+                day_override.short_break_mins,
+                day_override.long_break_mins,
+            ];
+            if mins.contains(&Some(0)) || day_override.long_break_interval == Some(0) {
+                return invalid(&format!("weekdays.{}: values must be at least 1", name));
+            }
+        }
+
+        let bounds = &self.bounds;
+        for (name, range) in [
+            ("focus", bounds.focus),
+            ("short_break", bounds.short_break),
+            ("long_break", bounds.long_break),
+            ("plain_timer", bounds.plain_timer),
+        ] {
+            if range.min == 0 || range.min > range.max {
+                return invalid(&format!(
+                    "bounds.{}: min must be at least 1 and no more than max",
+                    name
+                ));
+            }
+        }
+
+        let alerts = &self.alerts;
+        for (name, sound) in [
+            ("alerts.focus.sound", &alerts.focus.sound),
+            ("alerts.short_break.sound", &alerts.short_break.sound),
+            ("alerts.long_break.sound", &alerts.long_break.sound),
+            ("alerts.custom_break.sound", &alerts.custom_break.sound),
+            ("goal_sound", &self.goal_sound),
+            ("quotes_file", &self.quotes_file),
+        ] {
+            if let Some(sound) = sound
+                && !sound.is_file()
+            {
+                return invalid(&format!("{}: {} not found", name, sound.display()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Profile names become file names, so keep them to a safe character set.
+fn validate_profile_name(name: &str) -> io::Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid profile name '{}' (use letters, digits, '-' or '_')",
+                name
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alert_bundle_is_picked_for_the_starting_phase() {
+        let config: Config = toml::from_str(
+            r#"
+            [alerts.focus]
+            summary = "Back to work"
+            [alerts.long_break]
+            body = "Stretch: {phase}"
+            urgency = "critical"
+            "#,
+        )
+        .unwrap();
+        let alerts = &config.alerts;
+
+        assert_eq!(alerts.for_phase(Phase::Focus).summary, "Back to work");
+        let long_break = alerts.for_phase(Phase::LongBreak);
+        assert_eq!(long_break.body, "Stretch: {phase}");
+        assert_eq!(long_break.urgency, Urgency::Critical);
+        // Unset phases keep the default alert
+        assert_eq!(alerts.for_phase(Phase::ShortBreak).summary, "Phase Changed");
+        assert_eq!(
+            alerts.for_phase(Phase::CustomBreak).body,
+            "Starting {phase}"
+        );
+    }
+
+    fn weekend_config() -> Config {
+        toml::from_str(
+            r#"
+            focus_mins = 30
+            [weekdays.sat]
+            focus_mins = 50
+            short_break_mins = 15
+            long_break_mins = 30
+            [weekdays.Sunday]
+            long_break_interval = 2
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn weekday_override_replaces_the_values_it_sets() {
+        let config = weekend_config();
+        config.validate().unwrap();
+
+        let saturday = config.for_weekday(Weekday::Sat);
+        assert_eq!(saturday.focus_mins, 50);
+        assert_eq!(saturday.short_break_mins, 15);
+        assert_eq!(saturday.long_break_mins, 30);
+        assert_eq!(saturday.long_break_interval, 4);
+
+        // Full day names work too, and unset fields keep the base value
+        let sunday = config.for_weekday(Weekday::Sun);
+        assert_eq!(sunday.focus_mins, 30);
+        assert_eq!(sunday.long_break_interval, 2);
+    }
+
+    #[test]
+    fn days_without_an_override_use_the_base_config() {
+        let monday = weekend_config().for_weekday(Weekday::Mon);
+        assert_eq!(monday.focus_mins, 30);
+        assert_eq!(monday.short_break_mins, 5);
+        assert_eq!(monday.long_break_interval, 4);
+    }
+
+    #[test]
+    fn unknown_weekday_names_are_rejected() {
+        let mut config = weekend_config();
+        config
+            .weekdays
+            .insert("someday".to_string(), DayOverride::default());
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn quiet_hours_silence_sounds_within_the_window() {
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        let mut config: Config =
+            toml::from_str("[quiet_hours]\nstart = \"22:00\"\nend = \"07:00\"").unwrap();
+        assert!(config.silenced(time(23)));
+        assert!(config.silenced(time(3)));
+        assert!(!config.silenced(time(7)));
+        assert!(!config.silenced(time(12)));
+
+        config.quiet_hours = Some(QuietHours {
+            start: time(12),
+            end: time(14),
+        });
+        assert!(config.silenced(time(13)));
+        assert!(!config.silenced(time(23)));
+
+        config.quiet_hours = None;
+        assert!(!config.silenced(time(23)));
+        config.mute = true;
+        assert!(config.silenced(time(12)));
+    }
+}
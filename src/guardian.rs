@@ -0,0 +1,100 @@
+use std::{
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::Duration,
+};
+
+// --- Focus Guardian ---
+
+/// Polls the active window title on a background thread and reports changes,
+/// so the render loop never blocks on the (platform-specific) lookup.
+pub struct Guardian {
+    titles: Receiver<String>,
+}
+
+impl Guardian {
+    /// Starts polling. Returns `None` on platforms without an active-window lookup.
+    pub fn spawn(poll_interval: Duration) -> Option<Self> {
+        if !SUPPORTED {
+            return None;
+        }
+
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let mut last_title = String::new();
+            loop {
+                if let Some(title) = active_window_title()
+                    && title != last_title
+                {
+                    // The receiver is gone once the app quits
+                    if tx.send(title.clone()).is_err() {
+                        return;
+                    }
+                    last_title = title;
+                }
+                thread::sleep(poll_interval);
+            }
+        });
+
+        Some(Self { titles: rx })
+    }
+
+    /// Returns the most recent window title change since the last call, if any.
+    pub fn latest_title(&self) -> Option<String> {
+        self.titles.try_iter().last()
+    }
+}
+
+/// Case-insensitive substring match of a window title against the blocklist.
+pub fn is_distracting(title: &str, blocklist: &[String]) -> bool {
+    let title = title.to_lowercase();
+    blocklist
+        .iter()
+        .filter(|entry| !entry.is_empty())
+        .any(|entry| title.contains(&entry.to_lowercase()))
+}
+
+// --- Platform Lookups ---
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+const SUPPORTED: bool = true;
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+const SUPPORTED: bool = false;
+
+/// X11 (and XWayland windows) via `xdotool`.
+#[cfg(target_os = "linux")]
+fn active_window_title() -> Option<String> {
+    command_output("xdotool", &["getactivewindow", "getwindowname"])
+}
+
+/// Frontmost application name via AppleScript.
+#[cfg(target_os = "macos")]
+fn active_window_title() -> Option<String> {
+    command_output(
+        "osascript",
+        &[
+            "-e",
+            "tell application \"System Events\" to get name of first application process whose frontmost is true",
+        ],
+    )
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn active_window_title() -> Option<String> {
+    None
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let title = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!title.is_empty()).then_some(title)
+}
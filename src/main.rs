@@ -1,30 +1,113 @@
 use crossterm::{
-    event::{self, Event, KeyCode},
+    event::{
+        self, Event, KeyCode, KeyModifiers, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+        PushKeyboardEnhancementFlags,
+    },
     execute,
-    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+    terminal::{
+        EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+        supports_keyboard_enhancement,
+    },
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
-use std::{io, time::Duration};
+use std::{
+    io,
+    process::Command,
+    time::{Duration, Instant},
+};
 
 // Import our custom modules
 mod app;
+mod cli;
+mod clipboard;
+mod config;
+mod guardian;
+mod metrics;
+mod pair;
+mod quotes;
+mod snapshot;
+mod sound;
+mod stats;
+mod status;
 mod ui;
+mod ui_state;
 
 use app::{App, AppTab, Phase};
+use cli::Args;
+use config::Config;
+use guardian::Guardian;
+use pair::{Pair, PairEvent, SyncMessage};
 
 fn main() -> Result<(), io::Error> {
+    let args = Args::parse()?;
+    if args.help {
+        println!("{}", Args::usage());
+        return Ok(());
+    }
+
+    // Load Configuration (before touching the terminal, so errors stay readable)
+    let config = Config::load(args.profile.as_deref())?;
+
+    // Status bar query: print the running timer's status line and exit
+    if args.oneshot {
+        if let Some(state) = status::read()? {
+            println!("{}", state.render(&config.status_format));
+        }
+        return Ok(());
+    }
+    let history = stats::load()?;
+
+    // Prometheus export: write (or print) the metrics and exit
+    if args.metrics {
+        let text = metrics::render(&history);
+        match &config.metrics_path {
+            Some(path) => metrics::write(path, &text)?,
+            None => print!("{}", text),
+        }
+        return Ok(());
+    }
+
+    let mut app = App::new(config, history);
+    app.restore_ui_state(ui_state::load());
+    app.plain_forced = args.plain;
+    app.plain = app.plain || args.plain;
+    app.snapshots_enabled = args.snapshots;
+    app.current_task = args.task.filter(|task| !task.trim().is_empty());
+    if let Some(task) = app.current_task.clone() {
+        app.remember_task(task);
+    }
+    app.offer_last_task();
+    if let Some(remaining) = args.remaining {
+        app.set_remaining(remaining)?;
+    }
+    if args.start_running {
+        app.toggle_timer();
+    }
+
+    // Pair mode (binding happens here, so a busy port is reported before the TUI starts)
+    let pair = match (&args.listen, &args.connect) {
+        (Some(addr), _) => Some(Pair::listen(addr)?),
+        (None, Some(addr)) => Some(Pair::connect(addr)),
+        (None, None) => None,
+    };
+
     // Setup Terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
+    let enhanced_keys = enhance_keyboard()?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // App Loop
-    let mut app = App::new();
-    let res = run_app(&mut terminal, &mut app);
+    let res = run_app(&mut terminal, &mut app, pair);
+    let _ = app.save_ui_state();
+    let _ = status::clear();
 
     // Restore Terminal
+    if enhanced_keys {
+        execute!(terminal.backend_mut(), PopKeyboardEnhancementFlags)?;
+    }
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
@@ -39,60 +122,310 @@ fn main() -> Result<(), io::Error> {
 fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
+    pair: Option<Pair>,
 ) -> io::Result<()> {
+    let guardian = if app.config.focus_guardian {
+        Guardian::spawn(Duration::from_secs(app.config.guardian_poll_secs))
+    } else {
+        None
+    };
+    let mut tick_minutes = None; // Remaining minutes at the last minute-tick check
+    let mut status_written: Option<Instant> = None;
+    let mut last_activity = Instant::now(); // Last key press, or the timer still running
+    let mut synced = (app.phase, app.running); // Timer state last shared with the pair peer
+
     loop {
         // Draw the UI using the external ui module
-        terminal.draw(|f| ui::ui(f, app))?;
+        let frame = terminal.draw(|f| ui::ui(f, app))?;
+
+        // Save the frame that was just drawn, if a snapshot was requested
+        if app.snapshot_requested {
+            app.snapshot_requested = false;
+            match snapshot::save(frame.buffer) {
+                Ok(path) => app.flash(format!("Snapshot saved to {}", path.display())),
+                Err(err) => app.flash(format!("Snapshot failed: {}", err)),
+            }
+        }
 
         // Check for Auto-Complete and auto-transition to the next phase
-        if app.running && app.get_remaining().is_zero() {
-            app.next_phase();
+        if app.auto_complete_due() {
+            if app.plain {
+                app.finish_countdown();
+            } else {
+                app.next_phase();
+            }
+        }
+
+        app.check_stats_return();
+        app.check_first_focus(chrono::Local::now());
+
+        // Status export for `--oneshot` (about once per second)
+        if status_written.is_none_or(|at| at.elapsed() >= Duration::from_secs(1)) {
+            let _ = status::write(&status::StatusState::from_app(app));
+            status_written = Some(Instant::now());
+        }
+
+        // Minute tick during focus
+        if app.minute_tick_enabled() {
+            let minutes = app.remaining_minutes();
+            if app::minute_boundary_crossed(tick_minutes, minutes) {
+                sound::bell();
+            }
+            tick_minutes = Some(minutes);
+        } else {
+            tick_minutes = None;
+        }
+
+        // Focus Guardian: react to active window changes
+        if let Some(title) = guardian.as_ref().and_then(Guardian::latest_title) {
+            app.check_active_window(&title);
+        }
+
+        // Pair mode: share our changes, then mirror the peer's
+        if let Some(pair) = &pair {
+            if (app.phase, app.running) != synced {
+                pair.send(SyncMessage::from_app(app));
+            }
+            for event in pair.events() {
+                match event {
+                    PairEvent::Connected => {
+                        app.flash("Pair connected");
+                        if pair.host {
+                            pair.send(SyncMessage::from_app(app));
+                        }
+                    }
+                    PairEvent::Disconnected => app.flash("Pair disconnected, continuing solo"),
+                    PairEvent::Sync(message) => app.apply_sync(&message),
+                }
+            }
+            synced = (app.phase, app.running);
+        }
+
+        // Auto-quit when left stopped (paused or ready) and untouched
+        if app.running {
+            last_activity = Instant::now();
+        }
+        if app::idle_quit_due(last_activity.elapsed(), app.config.auto_quit_idle_mins) {
+            return Ok(());
         }
 
         // Handle Inputs
-        if event::poll(Duration::from_millis(250))? {
-            if let Event::Key(key) = event::read()? {
-                // Global Keys
+        let event = if event::poll(Duration::from_millis(250))? {
+            Some(event::read()?)
+        } else {
+            None
+        };
+        // Redraw at the new size right away rather than after the next poll
+        if let Some(Event::Resize(_, _)) = event {
+            terminal.autoresize()?;
+            continue;
+        }
+        if let Some(Event::Key(key)) = event {
+            last_activity = Instant::now();
+
+            // Global Keys (quit always works, even during a strict break)
+            let ctrl_c =
+                key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+            if ctrl_c || (key.code == KeyCode::Char('q') && app.confirm_quit()) {
+                return Ok(());
+            }
+
+            // First-run tutorial overlay captures navigation keys
+            if app.tutorial_step.is_some() {
                 match key.code {
-                    KeyCode::Char('q') => return Ok(()),
-                    KeyCode::Tab => {
-                        app.current_tab = match app.current_tab {
-                            AppTab::Timer => AppTab::Settings,
-                            AppTab::Settings => AppTab::Timer,
-                        }
+                    KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter => {
+                        app.next_tutorial_step()
                     }
+                    KeyCode::Left | KeyCode::Char('h') => app.prev_tutorial_step(),
+                    KeyCode::Esc => app.finish_tutorial(),
                     _ => {}
                 }
+                continue;
+            }
 
-                // Context Keys
-                match app.current_tab {
-                    AppTab::Timer => match key.code {
-                        KeyCode::Char(' ') => app.toggle_timer(),
-                        KeyCode::Char('n') => app.next_phase(),
-                        KeyCode::Char('r') => app.reset_timer(),
-                        KeyCode::Char('1') => {
-                            app.phase = Phase::Focus;
-                            app.reset_timer();
-                        }
-                        KeyCode::Char('2') => {
-                            app.phase = Phase::ShortBreak;
-                            app.reset_timer();
-                        }
-                        KeyCode::Char('3') => {
-                            app.phase = Phase::LongBreak;
-                            app.reset_timer();
-                        }
-                        _ => {}
-                    },
-                    AppTab::Settings => match key.code {
-                        KeyCode::Up | KeyCode::Char('k') => app.prev_setting(),
-                        KeyCode::Down | KeyCode::Char('j') => app.next_setting(),
-                        KeyCode::Left | KeyCode::Char('h') => app.adjust_setting(-5),
-                        KeyCode::Right | KeyCode::Char('l') => app.adjust_setting(5),
-                        _ => {}
-                    },
+            // Resume the previous run's task?
+            if app.resume_task_prompt {
+                match key.code {
+                    KeyCode::Char('y') | KeyCode::Enter => app.resume_last_task(true),
+                    KeyCode::Char('n') | KeyCode::Esc => app.resume_last_task(false),
+                    _ => {}
+                }
+                continue;
+            }
+
+            // Interruption reason: a number picks a tag, Esc/Enter skips it
+            if app.interrupt_prompt {
+                match key.code {
+                    KeyCode::Char(c @ '1'..='9') => {
+                        app.tag_interruption(Some(c as usize - '1' as usize))
+                    }
+                    KeyCode::Esc | KeyCode::Enter => app.tag_interruption(None),
+                    _ => {}
+                }
+                continue;
+            }
+
+            // Reset-to-defaults confirmation
+            if app.reset_prompt {
+                match key.code {
+                    KeyCode::Char('y') => app.reset_to_defaults(false),
+                    KeyCode::Char('d') => app.reset_to_defaults(true),
+                    KeyCode::Esc | KeyCode::Char('n') => app.reset_prompt = false,
+                    _ => {}
+                }
+                continue;
+            }
+
+            // Long Break prompt waits for a decision
+            if app.long_break_prompt {
+                match key.code {
+                    KeyCode::Char('s') => app.skip_long_break(),
+                    KeyCode::Enter => app.start_long_break(),
+                    _ => {}
+                }
+                continue;
+            }
+
+            // Strict Break: only pausing the break is allowed
+            if app.is_locked_out() {
+                if key.code == KeyCode::Char(' ') {
+                    app.toggle_timer();
+                }
+                continue;
+            }
+
+            // Ctrl+Shift+R asks to reset every setting. Plain Ctrl+R doesn't:
+            // terminals that can't report Shift with Ctrl use [R] on the
+            // Settings tab instead
+            let shift =
+                key.modifiers.contains(KeyModifiers::SHIFT) || key.code == KeyCode::Char('R');
+            if key.modifiers.contains(KeyModifiers::CONTROL)
+                && shift
+                && matches!(key.code, KeyCode::Char('r' | 'R'))
+            {
+                app.reset_prompt = true;
+                continue;
+            }
+
+            // Interrupt: pause and log it, from any tab
+            if key.code == KeyCode::Char('i') {
+                app.interrupt();
+                continue;
+            }
+
+            if app.snapshots_enabled && key.code == KeyCode::Char('p') {
+                app.snapshot_requested = true;
+            }
+
+            if key.code == KeyCode::Tab {
+                app.stats_return_at = None; // Manual navigation wins over the auto-return
+                app.current_tab = match app.current_tab {
+                    AppTab::Timer => AppTab::Settings,
+                    AppTab::Settings => AppTab::Stats,
+                    AppTab::Stats => AppTab::Timer,
+                }
+            }
+
+            // Context Keys
+            match app.current_tab {
+                AppTab::Timer => match key.code {
+                    KeyCode::Char(' ') => app.toggle_timer(),
+                    // Plain Timer has no phases to switch between
+                    KeyCode::Char('n' | 'b' | 'd' | '1' | '2' | '3') if app.plain => {}
+                    KeyCode::Char('n') => app.next_phase(),
+                    KeyCode::Char('f') => app.fast_forward_break(),
+                    KeyCode::Char('d') => app.toggle_deep_work(),
+                    KeyCode::Char('r') => app.reset_timer(),
+                    KeyCode::Char('g') => app.gauge_inverted = !app.gauge_inverted,
+                    KeyCode::Char('t') => app.cycle_task(),
+                    KeyCode::Char('b') => app.start_custom_break(),
+                    KeyCode::Char('1') => app.set_phase(Phase::Focus),
+                    KeyCode::Char('2') => app.set_phase(Phase::ShortBreak),
+                    KeyCode::Char('3') => app.set_phase(Phase::LongBreak),
+                    _ => {}
+                },
+                AppTab::Settings => match key.code {
+                    KeyCode::Up | KeyCode::Char('k') => app.prev_setting(),
+                    KeyCode::Down | KeyCode::Char('j') => app.next_setting(),
+                    KeyCode::Left | KeyCode::Char('h') => app.adjust_setting(-5),
+                    KeyCode::Right | KeyCode::Char('l') => app.adjust_setting(5),
+                    KeyCode::Char('e') => edit_config(terminal, app)?,
+                    KeyCode::Char('R') => app.reset_prompt = true,
+                    _ => {}
+                },
+                AppTab::Stats => {
+                    if key.code == KeyCode::Char('c') {
+                        app.copy_summary();
+                    }
                 }
             }
         }
     }
 }
+
+/// [E] on the Settings tab: suspends the TUI, opens the config file in
+/// `$VISUAL`/`$EDITOR`, then reloads it. An invalid file keeps the old config.
+fn edit_config<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+) -> io::Result<()> {
+    let Some(editor) = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|editor| !editor.trim().is_empty())
+    else {
+        app.flash("Set $EDITOR to edit the config from here");
+        return Ok(());
+    };
+    let Some(path) = Config::path(app.config.profile.as_deref()) else {
+        app.flash("No config directory on this platform");
+        return Ok(());
+    };
+    // Give the editor something to start from
+    if !path.exists()
+        && let Err(err) = app.config.save()
+    {
+        app.flash(format!("Couldn't create {}: {}", path.display(), err));
+        return Ok(());
+    }
+
+    // `$EDITOR` may carry arguments, e.g. "code --wait"
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or_default();
+
+    let enhanced_keys = matches!(supports_keyboard_enhancement(), Ok(true));
+    if enhanced_keys {
+        execute!(io::stdout(), PopKeyboardEnhancementFlags)?;
+    }
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen)?;
+    let status = Command::new(program).args(words).arg(&path).status();
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    if enhanced_keys {
+        enhance_keyboard()?;
+    }
+    terminal.clear()?;
+
+    match status {
+        Err(err) => app.flash(format!("Couldn't run {}: {}", program, err)),
+        Ok(_) => match Config::load(app.config.profile.as_deref()) {
+            Ok(config) => app.reload_config(config),
+            Err(err) => app.flash(format!("Config not reloaded: {}", err)),
+        },
+    }
+    Ok(())
+}
+
+/// Asks the terminal to report modifiers it would otherwise fold away, such as
+/// Shift on Ctrl+Shift+R. Returns whether it supports that (and must be reset).
+fn enhance_keyboard() -> io::Result<bool> {
+    if !matches!(supports_keyboard_enhancement(), Ok(true)) {
+        return Ok(false);
+    }
+    execute!(
+        io::stdout(),
+        PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+    )?;
+    Ok(true)
+}
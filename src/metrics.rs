@@ -0,0 +1,179 @@
+use std::{fmt::Write as _, fs, io, path::Path};
+
+use crate::app::Phase;
+use crate::stats::{self, SessionRecord};
+
+// --- Prometheus Export ---
+
+/// Renders the session log in the Prometheus text exposition format, for the
+/// node_exporter textfile collector.
+pub fn render(records: &[SessionRecord]) -> String {
+    let today = stats::today(records);
+    let break_time: u64 = records
+        .iter()
+        .filter(|record| record.phase.is_break())
+        .map(|record| record.active_secs)
+        .sum();
+    let interrupted = records
+        .iter()
+        .filter(|record| record.phase == Phase::Focus && !record.counted)
+        .count();
+
+    let mut out = String::new();
+    metric_header(
+        &mut out,
+        "pomodoro_completed_total",
+        "counter",
+        "Focus sessions counted as pomodoros.",
+    );
+    let mut by_profile = stats::pomodoros_by_profile(records);
+    if by_profile.is_empty() {
+        by_profile.push(("default".to_string(), 0));
+    }
+    for (profile, count) in by_profile {
+        let _ = writeln!(
+            out,
+            "pomodoro_completed_total{{profile=\"{}\"}} {}",
+            escape_label(&profile),
+            count
+        );
+    }
+    metric(
+        &mut out,
+        "pomodoro_interrupted_total",
+        "counter",
+        "Focus sessions skipped before they counted.",
+        interrupted,
+    );
+    metric(
+        &mut out,
+        "pomodoro_focus_minutes_total",
+        "counter",
+        "Active focus time in minutes.",
+        stats::focus_time(records).as_secs() / 60,
+    );
+    metric(
+        &mut out,
+        "pomodoro_break_minutes_total",
+        "counter",
+        "Active break time in minutes.",
+        break_time / 60,
+    );
+    metric(
+        &mut out,
+        "pomodoro_today_completed",
+        "gauge",
+        "Pomodoros counted today.",
+        stats::pomodoros(&today).count(),
+    );
+    metric(
+        &mut out,
+        "pomodoro_today_focus_minutes",
+        "gauge",
+        "Active focus time today in minutes.",
+        stats::focus_time(&today).as_secs() / 60,
+    );
+    out
+}
+
+fn metric_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn metric(out: &mut String, name: &str, kind: &str, help: &str, value: impl std::fmt::Display) {
+    metric_header(out, name, kind, help);
+    let _ = writeln!(out, "{} {}", name, value);
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Writes through a temporary file and renames it into place, so the collector
+/// never reads a half-written file.
+pub fn write(path: &Path, text: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("prom.tmp");
+    fs::write(&tmp, text)?;
+    fs::rename(tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration as ChronoDuration, Local};
+    use std::time::Duration;
+
+    fn record(
+        phase: Phase,
+        active_mins: u64,
+        counted: bool,
+        profile: Option<&str>,
+    ) -> SessionRecord {
+        let ended_at = Local::now();
+        let mut record = SessionRecord::new(
+            phase,
+            ended_at - ChronoDuration::minutes(active_mins as i64),
+            ended_at,
+            Duration::from_secs(25 * 60),
+            Duration::from_secs(active_mins * 60),
+            counted,
+        );
+        record.counted = counted;
+        record.profile = profile.map(String::from);
+        record
+    }
+
+    #[test]
+    fn render_exports_the_session_log() {
+        let records = [
+            record(Phase::Focus, 25, true, None),
+            record(Phase::ShortBreak, 5, true, None),
+            record(Phase::Focus, 25, true, Some("work \"deep\"")),
+            record(Phase::Focus, 10, false, None),
+        ];
+        assert_eq!(
+            render(&records),
+            "\
+# HELP pomodoro_completed_total Focus sessions counted as pomodoros.
+# TYPE pomodoro_completed_total counter
+pomodoro_completed_total{profile=\"default\"} 1
+pomodoro_completed_total{profile=\"work \\\"deep\\\"\"} 1
+# HELP pomodoro_interrupted_total Focus sessions skipped before they counted.
+# TYPE pomodoro_interrupted_total counter
+pomodoro_interrupted_total 1
+# HELP pomodoro_focus_minutes_total Active focus time in minutes.
+# TYPE pomodoro_focus_minutes_total counter
+pomodoro_focus_minutes_total 60
+# HELP pomodoro_break_minutes_total Active break time in minutes.
+# TYPE pomodoro_break_minutes_total counter
+pomodoro_break_minutes_total 5
+# HELP pomodoro_today_completed Pomodoros counted today.
+# TYPE pomodoro_today_completed gauge
+pomodoro_today_completed 2
+# HELP pomodoro_today_focus_minutes Active focus time today in minutes.
+# TYPE pomodoro_today_focus_minutes gauge
+pomodoro_today_focus_minutes 60
+"
+        );
+    }
+
+    #[test]
+    fn empty_log_still_reports_the_default_profile() {
+        assert!(render(&[]).contains("pomodoro_completed_total{profile=\"default\"} 0\n"));
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        assert_eq!(escape_label("plain"), "plain");
+        assert_eq!(escape_label(r#"a "b""#), r#"a \"b\""#);
+        assert_eq!(escape_label("back\\slash"), "back\\\\slash");
+        assert_eq!(escape_label("two\nlines"), "two\\nlines");
+    }
+}
@@ -0,0 +1,262 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::app::{App, Phase};
+
+// --- Pomodoro Pair ---
+
+/// How long to wait before dialing a dropped peer again.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Longest line accepted from the peer. A sync message is well under 200 bytes;
+/// anything longer drops the connection rather than filling up memory.
+const MAX_LINE_BYTES: u64 = 1024;
+
+/// Timer state shared with the peer whenever a phase starts, pauses or changes.
+/// Sent as one JSON object per line.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SyncMessage {
+    pub phase: Phase,
+    pub pomodoro_count: u8,
+    pub running: bool,
+    pub elapsed_ms: u64, // Time elapsed in the phase as of `sent_at`
+    pub sent_at: DateTime<Local>,
+}
+
+impl SyncMessage {
+    pub fn from_app(app: &App) -> Self {
+        Self {
+            phase: app.phase,
+            pomodoro_count: app.pomodoro_count,
+            running: app.running,
+            elapsed_ms: app.get_elapsed().as_millis() as u64,
+            sent_at: Local::now(),
+        }
+    }
+
+    /// Elapsed time now: a running timer kept going while the message was in
+    /// transit. Clock offset is only handled roughly: a peer whose clock runs
+    /// ahead of ours just counts as zero transit time.
+    pub fn elapsed_now(&self) -> Duration {
+        let elapsed = Duration::from_millis(self.elapsed_ms);
+        if !self.running {
+            return elapsed;
+        }
+        let transit = (Local::now() - self.sent_at).to_std().unwrap_or_default();
+        elapsed + transit
+    }
+
+    pub fn to_line(&self) -> io::Result<String> {
+        Ok(serde_json::to_string(self)? + "\n")
+    }
+
+    pub fn from_line(line: &str) -> io::Result<Self> {
+        Ok(serde_json::from_str(line.trim())?)
+    }
+}
+
+/// What the network thread reports back to the render loop.
+#[derive(Debug)]
+pub enum PairEvent {
+    Connected,
+    Disconnected,
+    Sync(SyncMessage),
+}
+
+/// Connection to a study buddy, run on background threads so the render loop
+/// never blocks on the network. When the peer drops, the timer simply carries
+/// on solo while the connection is re-established.
+pub struct Pair {
+    pub host: bool, // `--listen` side: sends its state to each peer that connects
+    events: Receiver<PairEvent>,
+    outgoing: Sender<SyncMessage>,
+}
+
+impl Pair {
+    /// `--listen`: waits for peers on `addr`, one at a time. There is no
+    /// handshake: anyone who can reach `addr` can drive the timer and add to the
+    /// session log, so it should only be exposed on a trusted network.
+    pub fn listen(addr: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        Ok(Self::spawn(true, move || {
+            listener.accept().map(|(stream, _)| stream)
+        }))
+    }
+
+    /// `--connect`: dials `addr`, retrying until the peer is reachable.
+    pub fn connect(addr: &str) -> Self {
+        let addr = addr.to_string();
+        Self::spawn(false, move || {
+            TcpStream::connect(&addr).inspect_err(|_| thread::sleep(RECONNECT_DELAY))
+        })
+    }
+
+    fn spawn(
+        host: bool,
+        mut establish: impl FnMut() -> io::Result<TcpStream> + Send + 'static,
+    ) -> Self {
+        let (events_tx, events_rx) = mpsc::channel();
+        let (outgoing_tx, outgoing_rx) = mpsc::channel::<SyncMessage>();
+
+        thread::spawn(move || {
+            loop {
+                let Ok(stream) = establish() else {
+                    continue;
+                };
+                // Drop anything queued while nobody was listening
+                while outgoing_rx.try_recv().is_ok() {}
+                if events_tx.send(PairEvent::Connected).is_err() {
+                    return; // The app has quit
+                }
+
+                serve(stream, &events_tx, &outgoing_rx);
+                if events_tx.send(PairEvent::Disconnected).is_err() {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            host,
+            events: events_rx,
+            outgoing: outgoing_tx,
+        }
+    }
+
+    /// Events received since the last call.
+    pub fn events(&self) -> Vec<PairEvent> {
+        self.events.try_iter().collect()
+    }
+
+    pub fn send(&self, message: SyncMessage) {
+        let _ = self.outgoing.send(message);
+    }
+}
+
+/// Runs one connection until either side drops it: a reader thread forwards
+/// incoming messages, while this thread writes outgoing ones.
+fn serve(stream: TcpStream, events: &Sender<PairEvent>, outgoing: &Receiver<SyncMessage>) {
+    let Ok(reader) = stream.try_clone() else {
+        return;
+    };
+    let alive = Arc::new(AtomicBool::new(true));
+
+    let reader_alive = Arc::clone(&alive);
+    let reader_events = events.clone();
+    thread::spawn(move || {
+        let mut reader = BufReader::new(reader);
+        while let Ok(Some(line)) = read_line(&mut reader) {
+            // Skip anything that isn't a sync message rather than dropping the peer
+            if let Ok(message) = SyncMessage::from_line(&line)
+                && reader_events.send(PairEvent::Sync(message)).is_err()
+            {
+                break;
+            }
+        }
+        reader_alive.store(false, Ordering::Relaxed);
+    });
+
+    let mut writer = stream;
+    while alive.load(Ordering::Relaxed) {
+        match outgoing.recv_timeout(Duration::from_secs(1)) {
+            Ok(message) => {
+                let sent = message
+                    .to_line()
+                    .and_then(|line| writer.write_all(line.as_bytes()));
+                if sent.is_err() {
+                    break;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+    let _ = writer.shutdown(std::net::Shutdown::Both);
+}
+
+/// Reads one line of at most `MAX_LINE_BYTES`, or `None` once the peer is done.
+/// A longer line is an error, which ends the connection.
+fn read_line(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut line = String::new();
+    let read = reader.by_ref().take(MAX_LINE_BYTES).read_line(&mut line)?;
+    if read == 0 {
+        return Ok(None);
+    }
+    if read as u64 == MAX_LINE_BYTES && !line.ends_with('\n') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "pair message too long",
+        ));
+    }
+    Ok(Some(line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(running: bool, sent_at: DateTime<Local>) -> SyncMessage {
+        SyncMessage {
+            phase: Phase::ShortBreak,
+            pomodoro_count: 3,
+            running,
+            elapsed_ms: 90_000,
+            sent_at,
+        }
+    }
+
+    #[test]
+    fn message_survives_a_round_trip_as_one_line() {
+        let sent = message(true, Local::now());
+        let line = sent.to_line().unwrap();
+        assert!(line.ends_with('\n'));
+        assert_eq!(line.matches('\n').count(), 1);
+        assert_eq!(SyncMessage::from_line(&line).unwrap(), sent);
+    }
+
+    #[test]
+    fn anything_else_is_not_a_message() {
+        assert!(SyncMessage::from_line("hello\n").is_err());
+        assert!(SyncMessage::from_line(r#"{"phase": "Focus"}"#).is_err());
+        assert!(SyncMessage::from_line(r#"{"phase": "Nap", "pomodoro_count": 0, "running": false, "elapsed_ms": 0, "sent_at": "2026-10-15T09:00:00+02:00"}"#).is_err());
+    }
+
+    #[test]
+    fn elapsed_time_includes_transit_only_while_running() {
+        let sent_at = Local::now() - chrono::Duration::seconds(2);
+        assert_eq!(
+            message(false, sent_at).elapsed_now(),
+            Duration::from_secs(90)
+        );
+
+        let elapsed = message(true, sent_at).elapsed_now();
+        assert!(elapsed >= Duration::from_secs(92));
+        assert!(elapsed < Duration::from_secs(95));
+
+        // A peer clock running ahead of ours counts as no transit at all
+        let ahead = Local::now() + chrono::Duration::minutes(5);
+        assert_eq!(message(true, ahead).elapsed_now(), Duration::from_secs(90));
+    }
+
+    #[test]
+    fn overlong_lines_end_the_connection() {
+        let mut input = io::Cursor::new(format!("short\n{}\n", "x".repeat(5000)));
+        assert_eq!(read_line(&mut input).unwrap().as_deref(), Some("short\n"));
+        assert!(read_line(&mut input).is_err());
+
+        let mut input = io::Cursor::new("last line without newline");
+        assert!(read_line(&mut input).unwrap().is_some());
+        assert_eq!(read_line(&mut input).unwrap(), None);
+    }
+}
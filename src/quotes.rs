@@ -0,0 +1,84 @@
+use std::fs;
+
+use crate::config::Config;
+
+// --- Break Quotes ---
+
+/// Bundled quotes, used unless `quotes` or `quotes_file` supply others.
+const DEFAULT_QUOTES: &[&str] = &[
+    "Almost everything will work again if you unplug it for a few minutes. — Anne Lamott",
+    "Rest is not idleness. — John Lubbock",
+    "The time to relax is when you don't have time for it. — Sydney J. Harris",
+    "Focus on being productive instead of busy. — Tim Ferriss",
+    "It always seems impossible until it's done. — Nelson Mandela",
+    "Take rest; a field that has rested gives a bountiful crop. — Ovid",
+    "Small steps every day add up to big results.",
+    "You can do anything, but not everything. — David Allen",
+];
+
+/// The quotes to draw from: `quotes_file` (one per line) if set and readable,
+/// else the `quotes` list, else the bundled set.
+pub fn load(config: &Config) -> Vec<String> {
+    let from_file = config
+        .quotes_file
+        .as_ref()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|contents| {
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect::<Vec<_>>()
+        })
+        .filter(|quotes| !quotes.is_empty());
+
+    from_file.unwrap_or_else(|| {
+        if config.quotes.is_empty() {
+            DEFAULT_QUOTES
+                .iter()
+                .map(|quote| quote.to_string())
+                .collect()
+        } else {
+            config.quotes.clone()
+        }
+    })
+}
+
+/// Quote for the break numbered `seed`: consecutive breaks rotate through the
+/// list, and the same break always gets the same quote.
+pub fn pick(quotes: &[String], seed: usize) -> Option<String> {
+    quotes.get(seed.checked_rem(quotes.len())?).cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quotes(list: &[&str]) -> Vec<String> {
+        list.iter().map(|quote| quote.to_string()).collect()
+    }
+
+    #[test]
+    fn pick_is_stable_per_seed_and_rotates() {
+        let list = quotes(&["a", "b", "c"]);
+        assert_eq!(pick(&list, 1), pick(&list, 1));
+        assert_eq!(pick(&list, 0).as_deref(), Some("a"));
+        assert_eq!(pick(&list, 1).as_deref(), Some("b"));
+        assert_eq!(pick(&list, 3).as_deref(), Some("a"));
+        assert_eq!(pick(&[], 0), None);
+    }
+
+    #[test]
+    fn configured_quotes_replace_the_bundled_set() {
+        let mut config = Config::default();
+        assert_eq!(load(&config).len(), DEFAULT_QUOTES.len());
+
+        config.quotes = quotes(&["Breathe."]);
+        assert_eq!(load(&config), ["Breathe."]);
+
+        // An unreadable file falls back to the list
+        config.quotes_file = Some("/nonexistent/quotes.txt".into());
+        assert_eq!(load(&config), ["Breathe."]);
+    }
+}
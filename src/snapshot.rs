@@ -0,0 +1,98 @@
+use chrono::Local;
+use ratatui::{buffer::Buffer, style::Color};
+use std::{fmt::Write as _, fs, io, path::PathBuf};
+
+// --- Screen Snapshots ---
+
+/// Writes the rendered frame to `pomodoro-<timestamp>.txt` and `.svg` in the
+/// current directory. Returns the text file's path.
+pub fn save(buffer: &Buffer) -> io::Result<PathBuf> {
+    let stem = format!("pomodoro-{}", Local::now().format("%Y%m%d-%H%M%S"));
+    let txt_path = PathBuf::from(format!("{}.txt", stem));
+
+    fs::write(&txt_path, to_text(buffer))?;
+    fs::write(format!("{}.svg", stem), to_svg(buffer))?;
+    Ok(txt_path)
+}
+
+/// Plain text: one line per row, trailing whitespace trimmed.
+pub fn to_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut text = String::new();
+    for y in area.top()..area.bottom() {
+        let line: String = (area.left()..area.right())
+            .map(|x| buffer[(x, y)].symbol())
+            .collect();
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    text
+}
+
+const CELL_WIDTH: u16 = 9;
+const CELL_HEIGHT: u16 = 18;
+
+/// Minimal SVG: a background and one `<text>` per row, colored per cell.
+pub fn to_svg(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="15">"#,
+        area.width * CELL_WIDTH,
+        area.height * CELL_HEIGHT
+    );
+    let _ = writeln!(
+        svg,
+        r##"<rect width="100%" height="100%" fill="#000000"/>"##
+    );
+
+    for y in area.top()..area.bottom() {
+        let _ = write!(
+            svg,
+            r#"<text y="{}" xml:space="preserve">"#,
+            (y - area.top() + 1) * CELL_HEIGHT - 4
+        );
+        for x in area.left()..area.right() {
+            let cell = &buffer[(x, y)];
+            if cell.symbol().trim().is_empty() {
+                continue;
+            }
+            let _ = write!(
+                svg,
+                r#"<tspan x="{}" fill="{}">{}</tspan>"#,
+                (x - area.left()) * CELL_WIDTH,
+                hex(cell.fg),
+                escape(cell.symbol())
+            );
+        }
+        let _ = writeln!(svg, "</text>");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn hex(color: Color) -> String {
+    let named = match color {
+        Color::Rgb(r, g, b) => return format!("#{:02x}{:02x}{:02x}", r, g, b),
+        Color::Black => "#000000",
+        Color::Red | Color::LightRed => "#e06c75",
+        Color::Green | Color::LightGreen => "#98c379",
+        Color::Yellow | Color::LightYellow => "#e5c07b",
+        Color::Blue | Color::LightBlue => "#61afef",
+        Color::Magenta | Color::LightMagenta => "#c678dd",
+        Color::Cyan | Color::LightCyan => "#56b6c2",
+        Color::DarkGray => "#5c6370",
+        Color::Gray => "#abb2bf",
+        _ => "#ffffff",
+    };
+    named.to_string()
+}
+
+fn escape(symbol: &str) -> String {
+    symbol
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -0,0 +1,42 @@
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+};
+
+// --- Sound Cues ---
+
+/// Rings the terminal bell. Quiet, dependency-free, and respects whatever the
+/// user's terminal does with BEL (beep, flash, or nothing).
+pub fn bell() {
+    let mut stdout = io::stdout();
+    let _ = stdout.write_all(b"\x07");
+    let _ = stdout.flush();
+}
+
+/// Plays a sound file with the platform's command-line player. Runs on its own
+/// thread so the UI never waits for playback.
+pub fn play(path: &Path) {
+    let path: PathBuf = path.to_path_buf();
+    thread::spawn(move || {
+        for player in PLAYERS {
+            let played = Command::new(player)
+                .arg(&path)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status();
+            // Fall through to the next player only if this one isn't installed
+            if played.is_ok() {
+                return;
+            }
+        }
+    });
+}
+
+#[cfg(target_os = "macos")]
+const PLAYERS: &[&str] = &["afplay"];
+#[cfg(all(unix, not(target_os = "macos")))]
+const PLAYERS: &[&str] = &["paplay", "aplay"];
+#[cfg(not(unix))]
+const PLAYERS: &[&str] = &[];
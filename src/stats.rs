@@ -0,0 +1,613 @@
+use chrono::{DateTime, Days, Local, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+use crate::app::Phase;
+
+// --- Session Log ---
+
+/// An [I] interruption: when the session was paused in a hurry, and why.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Interruption {
+    pub at: DateTime<Local>,
+    pub reason: Option<String>, // Quick tag from `interrupt_reasons`
+}
+
+/// One finished phase, as appended to the session log.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub phase: Phase,
+    pub started_at: DateTime<Local>, // First time the timer was started in this phase
+    pub ended_at: DateTime<Local>,
+    pub planned_secs: u64,
+    pub active_secs: u64, // Time the timer was actually running (pauses excluded)
+    pub span_secs: u64,   // Wall-clock span from first start to completion (pauses included)
+    #[serde(default)]
+    pub completed: bool, // The countdown ran all the way to zero (not skipped early)
+    #[serde(default = "default_counted")]
+    pub counted: bool, // Counted toward the pomodoro count (false = interrupted focus)
+    #[serde(default)]
+    pub distractions: Vec<String>, // Blocklisted windows switched to during the phase
+    #[serde(default)]
+    pub profile: Option<String>, // Config profile active at the time (None = default)
+    #[serde(default)]
+    pub task: Option<String>,
+    #[serde(default)]
+    pub interruptions: Vec<Interruption>,
+}
+
+impl SessionRecord {
+    pub fn new(
+        phase: Phase,
+        started_at: DateTime<Local>,
+        ended_at: DateTime<Local>,
+        planned: Duration,
+        active: Duration,
+        completed: bool,
+    ) -> Self {
+        let span = (ended_at - started_at).to_std().unwrap_or_default();
+        Self {
+            phase,
+            started_at,
+            ended_at,
+            planned_secs: planned.as_secs(),
+            active_secs: active.as_secs(),
+            span_secs: span.as_secs(),
+            completed,
+            counted: true,
+            distractions: Vec::new(),
+            profile: None,
+            task: None,
+            interruptions: Vec::new(),
+        }
+    }
+
+    pub fn is_today(&self) -> bool {
+        self.ended_at.date_naive() == Local::now().date_naive()
+    }
+
+    pub fn active(&self) -> Duration {
+        Duration::from_secs(self.active_secs)
+    }
+
+    /// Actual (active) time as a percentage of the planned time. Over 100 when
+    /// the phase ran past its plan, e.g. synced from a peer with longer phases.
+    pub fn adherence(&self) -> u64 {
+        (self.active_secs * 100)
+            .checked_div(self.planned_secs)
+            .unwrap_or(0)
+    }
+}
+
+fn default_counted() -> bool {
+    true
+}
+
+pub fn log_path() -> Option<PathBuf> {
+    // Tests log to memory only, never to the user's session log
+    if cfg!(test) {
+        return None;
+    }
+    dirs::data_dir().map(|dir| dir.join("pomodoro-tui").join("sessions.jsonl"))
+}
+
+/// Reads every record from the session log. Lines that fail to parse are skipped
+/// so one corrupt entry doesn't hide the rest of the history.
+pub fn load() -> io::Result<Vec<SessionRecord>> {
+    let Some(path) = log_path() else {
+        return Ok(Vec::new());
+    };
+
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Appends a single record (one JSON object per line) to the session log.
+pub fn append(record: &SessionRecord) -> io::Result<()> {
+    let Some(path) = log_path() else {
+        return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(record)?;
+    writeln!(file, "{}", line)
+}
+
+// --- Aggregation ---
+
+/// Records that ended today (local time).
+pub fn today(records: &[SessionRecord]) -> Vec<SessionRecord> {
+    records
+        .iter()
+        .filter(|record| record.is_today())
+        .cloned()
+        .collect()
+}
+
+/// The records of the current round: everything after the most recent long break.
+pub fn current_round(records: &[SessionRecord]) -> &[SessionRecord] {
+    let start = records
+        .iter()
+        .rposition(|record| record.phase == Phase::LongBreak)
+        .map_or(0, |index| index + 1);
+    &records[start..]
+}
+
+/// Pomodoros: focus sessions that counted toward the pomodoro count, in log order.
+pub fn pomodoros(records: &[SessionRecord]) -> impl Iterator<Item = &SessionRecord> {
+    records
+        .iter()
+        .filter(|record| record.phase == Phase::Focus && record.counted)
+}
+
+/// Attempts: every logged focus session, counted or interrupted.
+pub fn attempts(records: &[SessionRecord]) -> impl Iterator<Item = &SessionRecord> {
+    records.iter().filter(|record| record.phase == Phase::Focus)
+}
+
+pub fn focus_time(records: &[SessionRecord]) -> Duration {
+    records
+        .iter()
+        .filter(|record| record.phase == Phase::Focus)
+        .map(SessionRecord::active)
+        .sum()
+}
+
+/// Best focus streak: the longest chain of consecutive *completed* focus
+/// sessions, measured in active focus time. Breaks in between don't end a
+/// chain; a focus session that was skipped before the timer ran out does.
+pub fn best_focus_streak(records: &[SessionRecord]) -> Duration {
+    let mut best = Duration::ZERO;
+    let mut current = Duration::ZERO;
+
+    for record in records.iter().filter(|record| record.phase == Phase::Focus) {
+        if record.completed {
+            current += record.active();
+            best = best.max(current);
+        } else {
+            current = Duration::ZERO;
+        }
+    }
+    best
+}
+
+/// Day streak: consecutive days, up to `today`, with at least one pomodoro.
+/// A day without any yet doesn't break the streak until it's over.
+pub fn day_streak(records: &[SessionRecord], today: NaiveDate) -> u32 {
+    let days: Vec<NaiveDate> = pomodoros(records)
+        .map(|record| record.ended_at.date_naive())
+        .collect();
+    let mut day = today;
+    if !days.contains(&day) {
+        day = day - Days::new(1);
+    }
+
+    let mut streak = 0;
+    while days.contains(&day) {
+        streak += 1;
+        day = day - Days::new(1);
+    }
+    streak
+}
+
+/// Rest debt: planned break time that wasn't taken, from breaks skipped or cut
+/// short. Custom breaks sit outside the cycle, so they neither add to nor repay it.
+pub fn rest_debt(records: &[SessionRecord]) -> Duration {
+    records
+        .iter()
+        .filter(|record| matches!(record.phase, Phase::ShortBreak | Phase::LongBreak))
+        .map(|record| Duration::from_secs(record.planned_secs.saturating_sub(record.active_secs)))
+        .sum()
+}
+
+/// Break adherence: share of the planned break time actually rested, as a
+/// percentage (`None` without any breaks). Custom breaks are left out, like
+/// for the rest debt.
+pub fn break_adherence(records: &[SessionRecord]) -> Option<u64> {
+    let (planned, active) = records
+        .iter()
+        .filter(|record| matches!(record.phase, Phase::ShortBreak | Phase::LongBreak))
+        .fold((0, 0), |(planned, active), record| {
+            (
+                planned + record.planned_secs,
+                active + record.active_secs.min(record.planned_secs),
+            )
+        });
+    (active * 100).checked_div(planned)
+}
+
+/// Distinct task names, most recently logged first, at most `limit`.
+pub fn recent_tasks(records: &[SessionRecord], limit: usize) -> Vec<String> {
+    let mut tasks: Vec<String> = Vec::new();
+    for task in records
+        .iter()
+        .rev()
+        .filter_map(|record| record.task.as_ref())
+    {
+        if tasks.len() == limit {
+            break;
+        }
+        if !tasks.contains(task) {
+            tasks.push(task.clone());
+        }
+    }
+    tasks
+}
+
+/// Today's leaderboard: completed pomodoros per profile, most first.
+pub fn pomodoros_by_profile(records: &[SessionRecord]) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for record in pomodoros(records) {
+        let name = record.profile.as_deref().unwrap_or("default");
+        match counts.iter_mut().find(|(profile, _)| profile == name) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((name.to_string(), 1)),
+        }
+    }
+
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Progress toward the daily goal relative to how much of the working day has passed.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Pace {
+    NotStarted, // Before the start of the day, nothing done yet
+    OnTrack,
+    Behind(u32), // Sessions short of where we should be by now
+    GoalReached,
+}
+
+/// Pace: the goal is spread evenly over `day_start..day_end`, so by any time of
+/// day `goal * elapsed_fraction` sessions (rounded down) should be done. Before
+/// the day starts nothing is expected; after it ends, the whole goal is.
+pub fn pace(
+    done: u32,
+    goal: u32,
+    now: NaiveTime,
+    day_start: NaiveTime,
+    day_end: NaiveTime,
+) -> Pace {
+    if done >= goal {
+        return Pace::GoalReached;
+    }
+    if now < day_start {
+        return if done == 0 {
+            Pace::NotStarted
+        } else {
+            Pace::OnTrack
+        };
+    }
+
+    let day = (day_end - day_start).num_seconds().max(1) as f64;
+    let fraction = ((now - day_start).num_seconds() as f64 / day).min(1.0);
+    let expected = (f64::from(goal) * fraction).floor() as u32;
+
+    if done < expected {
+        Pace::Behind(expected - done)
+    } else {
+        Pace::OnTrack
+    }
+}
+
+/// One-line summary for sharing, e.g. "Today: 6🍅 2h 30m focused, 5-day streak".
+pub fn summary(records: &[SessionRecord], ascii: bool) -> String {
+    let today = self::today(records);
+    let count = pomodoros(&today).count();
+    let pomodoros = if ascii {
+        format!("{} pomodoros,", count)
+    } else {
+        format!("{}🍅", count)
+    };
+    format!(
+        "Today: {} {} focused, {}-day streak",
+        pomodoros,
+        format_minutes(focus_time(&today)),
+        day_streak(records, Local::now().date_naive())
+    )
+}
+
+/// Formats a duration as "2h 30m" (or "45m" under an hour).
+pub fn format_minutes(duration: Duration) -> String {
+    let mins = duration.as_secs() / 60;
+    if mins >= 60 {
+        format!("{}h {:02}m", mins / 60, mins % 60)
+    } else {
+        format!("{}m", mins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mins(mins: u64) -> Duration {
+        Duration::from_secs(mins * 60)
+    }
+
+    #[test]
+    fn paused_session_spans_more_than_it_was_active() {
+        // 25 minutes of focus with a 15 minute pause in the middle
+        let started_at = Local::now() - chrono::Duration::minutes(40);
+        let record = SessionRecord::new(
+            Phase::Focus,
+            started_at,
+            started_at + chrono::Duration::minutes(40),
+            mins(25),
+            mins(25),
+            true,
+        );
+
+        assert_eq!(record.active_secs, 25 * 60);
+        assert_eq!(record.span_secs, 40 * 60);
+    }
+
+    /// A record that ended just now.
+    fn record(phase: Phase, planned_mins: u64, active_mins: u64, completed: bool) -> SessionRecord {
+        let ended_at = Local::now();
+        SessionRecord::new(
+            phase,
+            ended_at - chrono::Duration::minutes(active_mins as i64),
+            ended_at,
+            mins(planned_mins),
+            mins(active_mins),
+            completed,
+        )
+    }
+
+    #[test]
+    fn focus_streak_chains_completed_sessions_across_breaks() {
+        let records = [
+            record(Phase::Focus, 25, 25, true),
+            record(Phase::ShortBreak, 5, 5, true),
+            record(Phase::Focus, 25, 25, true),
+            record(Phase::ShortBreak, 5, 1, false),
+            record(Phase::Focus, 50, 50, true),
+        ];
+        assert_eq!(best_focus_streak(&records), mins(100));
+    }
+
+    #[test]
+    fn interrupted_focus_breaks_the_streak() {
+        let records = [
+            record(Phase::Focus, 25, 25, true),
+            record(Phase::Focus, 25, 25, true),
+            record(Phase::Focus, 25, 10, false),
+            record(Phase::Focus, 25, 25, true),
+        ];
+        assert_eq!(best_focus_streak(&records), mins(50));
+
+        // The interrupted session's own time never counts
+        assert_eq!(
+            best_focus_streak(&[record(Phase::Focus, 25, 20, false)]),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn current_round_starts_after_the_last_long_break() {
+        let records = [
+            record(Phase::Focus, 25, 25, true),
+            record(Phase::LongBreak, 15, 15, true),
+            record(Phase::Focus, 25, 25, true),
+            record(Phase::ShortBreak, 5, 5, true),
+        ];
+        let round = current_round(&records);
+        assert_eq!(round.len(), 2);
+        assert_eq!(round[0].phase, Phase::Focus);
+
+        assert_eq!(current_round(&records[..2]).len(), 0);
+        assert_eq!(current_round(&records[..1]).len(), 1);
+    }
+
+    #[test]
+    fn pomodoros_are_grouped_by_profile_most_first() {
+        let tagged = |profile: Option<&str>, counted: bool| SessionRecord {
+            profile: profile.map(str::to_string),
+            counted,
+            ..record(Phase::Focus, 25, 25, true)
+        };
+        let records = [
+            tagged(Some("Study"), true),
+            tagged(None, true),
+            tagged(Some("Work"), true),
+            tagged(Some("Work"), true),
+            tagged(Some("Study"), false), // Interrupted: not a pomodoro
+            record(Phase::ShortBreak, 5, 5, true),
+        ];
+        assert_eq!(
+            pomodoros_by_profile(&records),
+            [
+                ("Work".to_string(), 2),
+                ("Study".to_string(), 1),
+                ("default".to_string(), 1),
+            ]
+        );
+        assert!(pomodoros_by_profile(&[]).is_empty());
+    }
+
+    fn at(hour: u32, min: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+    }
+
+    #[test]
+    fn pace_expects_nothing_before_the_day_starts() {
+        let (start, end) = (at(9, 0), at(17, 0));
+        assert_eq!(pace(0, 8, at(7, 30), start, end), Pace::NotStarted);
+        assert_eq!(pace(1, 8, at(7, 30), start, end), Pace::OnTrack);
+    }
+
+    #[test]
+    fn pace_expects_the_whole_goal_after_the_day_ends() {
+        let (start, end) = (at(9, 0), at(17, 0));
+        assert_eq!(pace(5, 8, at(17, 0), start, end), Pace::Behind(3));
+        assert_eq!(pace(5, 8, at(23, 0), start, end), Pace::Behind(3));
+        assert_eq!(pace(8, 8, at(23, 0), start, end), Pace::GoalReached);
+    }
+
+    #[test]
+    fn pace_spreads_the_goal_over_the_day() {
+        let (start, end) = (at(9, 0), at(17, 0));
+        // Halfway through: 4 of 8 expected
+        assert_eq!(pace(4, 8, at(13, 0), start, end), Pace::OnTrack);
+        assert_eq!(pace(3, 8, at(13, 0), start, end), Pace::Behind(1));
+        // Rounded down: 3.5 expected at 12:30
+        assert_eq!(pace(3, 8, at(12, 30), start, end), Pace::OnTrack);
+    }
+
+    #[test]
+    fn rest_debt_adds_up_skipped_and_cut_short_breaks() {
+        let records = [
+            record(Phase::ShortBreak, 5, 0, false),   // Skipped: 5m
+            record(Phase::ShortBreak, 5, 5, true),    // Taken in full
+            record(Phase::LongBreak, 15, 5, false),   // Cut short: 10m
+            record(Phase::CustomBreak, 30, 0, false), // Outside the cycle
+            record(Phase::Focus, 25, 10, false),
+        ];
+        assert_eq!(rest_debt(&records), mins(15));
+        assert_eq!(rest_debt(&[]), Duration::ZERO);
+    }
+
+    #[test]
+    fn break_adherence_is_full_for_breaks_taken_in_full() {
+        let records = [
+            record(Phase::ShortBreak, 5, 5, true),
+            record(Phase::LongBreak, 15, 15, true),
+            record(Phase::Focus, 25, 10, false),
+        ];
+        assert_eq!(break_adherence(&records), Some(100));
+    }
+
+    #[test]
+    fn break_adherence_drops_for_cut_short_breaks() {
+        let records = [
+            record(Phase::ShortBreak, 5, 5, true),
+            record(Phase::ShortBreak, 5, 0, false),
+            record(Phase::LongBreak, 10, 5, false),
+            record(Phase::CustomBreak, 30, 1, false), // Left out
+        ];
+        assert_eq!(break_adherence(&records), Some(50));
+        assert_eq!(break_adherence(&records[3..]), None);
+    }
+
+    #[test]
+    fn adherence_compares_actual_to_planned_time() {
+        assert_eq!(record(Phase::Focus, 25, 25, true).adherence(), 100);
+        assert_eq!(record(Phase::Focus, 25, 30, true).adherence(), 120);
+        assert_eq!(record(Phase::Focus, 25, 10, false).adherence(), 40);
+        assert_eq!(record(Phase::ShortBreak, 5, 0, false).adherence(), 0);
+        // Nothing planned: nothing to adhere to
+        assert_eq!(record(Phase::Focus, 0, 5, true).adherence(), 0);
+    }
+
+    #[test]
+    fn recent_tasks_are_most_recent_first_without_repeats() {
+        let records: Vec<SessionRecord> = ["Docs", "Parser", "Docs", "", "Review"]
+            .into_iter()
+            .map(|task| SessionRecord {
+                task: (!task.is_empty()).then(|| task.to_string()),
+                ..record(Phase::Focus, 25, 25, true)
+            })
+            .collect();
+        assert_eq!(recent_tasks(&records, 5), ["Review", "Docs", "Parser"]);
+        assert_eq!(recent_tasks(&records, 2), ["Review", "Docs"]);
+        assert!(recent_tasks(&[], 5).is_empty());
+    }
+
+    #[test]
+    fn attempts_include_interrupted_focus_but_pomodoros_do_not() {
+        let interrupted = SessionRecord {
+            counted: false,
+            ..record(Phase::Focus, 25, 5, false)
+        };
+        let records = [
+            record(Phase::Focus, 25, 25, true),
+            record(Phase::ShortBreak, 5, 5, true),
+            interrupted,
+            record(Phase::Focus, 25, 25, true),
+        ];
+        assert_eq!(pomodoros(&records).count(), 2);
+        assert_eq!(attempts(&records).count(), 3);
+        assert!(attempts(&records).all(|record| record.phase == Phase::Focus));
+    }
+
+    /// A counted pomodoro that ended `days_ago` days before `today`.
+    fn pomodoro_on(today: NaiveDate, days_ago: u64) -> SessionRecord {
+        let ended_at = (today - Days::new(days_ago))
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap();
+        SessionRecord {
+            ended_at,
+            ..record(Phase::Focus, 25, 25, true)
+        }
+    }
+
+    #[test]
+    fn day_streak_counts_back_from_today_or_yesterday() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).unwrap();
+        assert_eq!(day_streak(&[], today), 0);
+
+        let mut records = vec![
+            pomodoro_on(today, 4),
+            pomodoro_on(today, 2),
+            pomodoro_on(today, 1),
+        ];
+        // Nothing yet today: the streak still stands until the day is over
+        assert_eq!(day_streak(&records, today), 2);
+        records.push(pomodoro_on(today, 0));
+        records.push(pomodoro_on(today, 0));
+        assert_eq!(day_streak(&records, today), 3);
+
+        // A gap ends it, and interrupted focus doesn't keep it going
+        assert_eq!(day_streak(&records, today + Days::new(2)), 0);
+        let interrupted = SessionRecord {
+            counted: false,
+            ..pomodoro_on(today, 3)
+        };
+        records.push(interrupted);
+        assert_eq!(day_streak(&records, today), 3);
+    }
+
+    #[test]
+    fn summary_lists_todays_count_focus_time_and_streak() {
+        let today = Local::now().date_naive();
+        let records = [
+            pomodoro_on(today, 1),
+            record(Phase::Focus, 25, 25, true),
+            record(Phase::ShortBreak, 5, 5, true),
+            record(Phase::Focus, 50, 50, true),
+            record(Phase::Focus, 25, 10, false),
+        ];
+        assert_eq!(
+            summary(&records, false),
+            "Today: 3🍅 1h 25m focused, 2-day streak"
+        );
+        assert_eq!(
+            summary(&records, true),
+            "Today: 3 pomodoros, 1h 25m focused, 2-day streak"
+        );
+        assert_eq!(
+            summary(&[], true),
+            "Today: 0 pomodoros, 0m focused, 0-day streak"
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::{fs, io, path::PathBuf};
+
+use crate::app::App;
+
+// --- Status Export ---
+
+/// Snapshot of the running timer, written to `state.json` so status bars
+/// (tmux, waybar, ...) can query it with `--oneshot`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StatusState {
+    pub phase: String,
+    pub state: String, // READY / RUNNING / PAUSED
+    pub running: bool,
+    pub remaining_secs: u64, // As of `written_at`
+    pub target_secs: u64,
+    pub count: String, // e.g. "2/4"
+    pub written_at: DateTime<Local>,
+}
+
+impl StatusState {
+    pub fn from_app(app: &App) -> Self {
+        Self {
+            phase: app.phase_name(),
+            state: app.timer_state().label().to_string(),
+            running: app.running,
+            remaining_secs: app.remaining_display_secs(),
+            target_secs: app.get_target_duration().as_secs(),
+            count: format!(
+                "{}/{}",
+                app.pomodoro_count % app.long_break_interval,
+                app.long_break_interval
+            ),
+            written_at: Local::now(),
+        }
+    }
+
+    /// Remaining seconds now, accounting for time passed since the export.
+    pub fn remaining_now(&self) -> u64 {
+        if !self.running {
+            return self.remaining_secs;
+        }
+        let passed = (Local::now() - self.written_at).num_seconds().max(0) as u64;
+        self.remaining_secs.saturating_sub(passed)
+    }
+
+    /// Renders the status line from a format string such as
+    /// "{phase} {remaining} {percent} {count}".
+    pub fn render(&self, format: &str) -> String {
+        let remaining = self.remaining_now();
+        let percent = (self.target_secs.saturating_sub(remaining) * 100)
+            .checked_div(self.target_secs)
+            .unwrap_or(0);
+
+        format_tokens(
+            format,
+            &[
+                ("phase", self.phase.clone()),
+                ("state", self.state.clone()),
+                (
+                    "remaining",
+                    format!("{:02}:{:02}", remaining / 60, remaining % 60),
+                ),
+                ("percent", format!("{}%", percent)),
+                ("count", self.count.clone()),
+            ],
+        )
+    }
+}
+
+/// Replaces `{token}` placeholders with their values. Unknown tokens (and
+/// unmatched braces) are kept literally.
+pub fn format_tokens(format: &str, values: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(format.len());
+    let mut rest = format;
+
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        let replaced = after.find('}').and_then(|end| {
+            let token = &after[..end];
+            values
+                .iter()
+                .find(|(name, _)| *name == token)
+                .map(|(_, value)| (value, end))
+        });
+
+        match replaced {
+            Some((value, end)) => {
+                out.push_str(value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+pub fn state_path() -> Option<PathBuf> {
+    if cfg!(test) {
+        return None;
+    }
+    dirs::data_dir().map(|dir| dir.join("pomodoro-tui").join("state.json"))
+}
+
+pub fn write(state: &StatusState) -> io::Result<()> {
+    let Some(path) = state_path() else {
+        return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string(state)?)
+}
+
+/// Reads the exported state, or `None` when no timer is running.
+pub fn read() -> io::Result<Option<StatusState>> {
+    let Some(path) = state_path() else {
+        return Ok(None);
+    };
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(serde_json::from_str(&contents)?)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Removes the exported state on quit, so status bars don't show a stale timer.
+pub fn clear() -> io::Result<()> {
+    match state_path().map(fs::remove_file) {
+        Some(Err(err)) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> Vec<(&'static str, String)> {
+        vec![
+            ("phase", "FOCUS SESSION".to_string()),
+            ("remaining", "12:34".to_string()),
+            ("count", "2/4".to_string()),
+        ]
+    }
+
+    #[test]
+    fn tokens_are_replaced_in_any_combination() {
+        assert_eq!(
+            format_tokens("{phase} {remaining}", &values()),
+            "FOCUS SESSION 12:34"
+        );
+        assert_eq!(
+            format_tokens("🍅 {count} ({remaining}){remaining}", &values()),
+            "🍅 2/4 (12:34)12:34"
+        );
+        assert_eq!(format_tokens("no tokens", &values()), "no tokens");
+    }
+
+    #[test]
+    fn unknown_tokens_and_unmatched_braces_stay_literal() {
+        assert_eq!(
+            format_tokens("{phase} {nope}", &values()),
+            "FOCUS SESSION {nope}"
+        );
+        assert_eq!(format_tokens("{ {phase} }", &values()), "{ FOCUS SESSION }");
+        assert_eq!(format_tokens("{phase", &values()), "{phase");
+        assert_eq!(format_tokens("{{count}}", &values()), "{2/4}");
+    }
+
+    #[test]
+    fn render_fills_in_the_state() {
+        let state = StatusState {
+            phase: "SHORT BREAK".to_string(),
+            state: "PAUSED".to_string(),
+            running: false,
+            remaining_secs: 150,
+            target_secs: 300,
+            count: "1/4".to_string(),
+            written_at: Local::now(),
+        };
+        assert_eq!(
+            state.render("{phase} {state} {remaining} {percent} {count}"),
+            "SHORT BREAK PAUSED 02:30 50% 1/4"
+        );
+    }
+}
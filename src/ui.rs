@@ -1,18 +1,32 @@
+use chrono::Local;
+use figlet_rs::FIGlet;
 use ratatui::{
     Frame,
+    buffer::Buffer,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Gauge, Paragraph, Tabs},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Tabs, Widget, Wrap},
 };
 use tui_big_text::{BigText, PixelSize};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 // Import types from our application logic module
-use crate::app::{App, AppTab, SettingSelection};
+use crate::app::{App, AppTab, Phase, SettingSelection, TUTORIAL_STEPS, TimerState};
+use crate::config::{CycleDisplay, GaugeLabel, TimerFont};
+use crate::stats;
 
 // --- UI Rendering ---
 
 pub fn ui(f: &mut Frame, app: &App) {
     let size = f.area();
+
+    // Strict Break takes over the whole screen
+    if app.is_locked_out() {
+        draw_lockout(f, app, size);
+        return;
+    }
+
     // Main Container
     let main_block = Block::default()
         .borders(Borders::ALL)
@@ -31,17 +45,19 @@ pub fn ui(f: &mut Frame, app: &App) {
         .split(size);
 
     // Tabs
-    let titles = vec![" Timer ", " Settings "];
+    let titles = vec![" Timer ", " Settings ", " Stats "];
     let tab_style = match app.current_tab {
         AppTab::Timer => app.phase.color(),
         AppTab::Settings => Color::Cyan,
+        AppTab::Stats => Color::Magenta,
     };
 
     let tabs = Tabs::new(titles)
         .block(Block::default().borders(Borders::BOTTOM))
         .select(match app.current_tab {
             AppTab::Timer => 0,
-            _ => 1,
+            AppTab::Settings => 1,
+            AppTab::Stats => 2,
         })
         .highlight_style(Style::default().fg(tab_style).add_modifier(Modifier::BOLD));
     f.render_widget(tabs, chunks[0]);
@@ -50,43 +66,132 @@ pub fn ui(f: &mut Frame, app: &App) {
     match app.current_tab {
         AppTab::Timer => draw_timer_tab(f, app, chunks[1]),
         AppTab::Settings => draw_settings_tab(f, app, chunks[1]),
+        AppTab::Stats => draw_stats_tab(f, app, chunks[1]),
     };
 
     // Footer
-    let footer_text = match app.current_tab {
-        AppTab::Timer => {
-            "Controls: [Space] Toggle | [R] Reset | [N] Next Phase | [1/2/3] Set Phase | [Tab] Settings | [Q] Quit"
-        }
-        AppTab::Settings => {
-            "Controls: [Up/Down] Select | [Left/Right] Adjust (±5m) | [Tab] Back to Timer"
+    let footer_text = match app.flash_message() {
+        Some(message) => message.to_string(),
+        None => footer_text(app),
+    };
+    let footer_area = match footer_clock(app, &footer_text, chunks[2].width) {
+        Some(clock) => {
+            let [text_area, clock_area] = Layout::horizontal([
+                Constraint::Fill(1),
+                Constraint::Length(clock.width() as u16),
+            ])
+            .areas(chunks[2]);
+            let clock = Paragraph::new(clock)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Right);
+            f.render_widget(clock, clock_area);
+            text_area
         }
+        None => chunks[2],
     };
     let footer = Paragraph::new(footer_text)
         .style(Style::default().fg(Color::DarkGray))
         .alignment(Alignment::Center);
-    f.render_widget(footer, chunks[2]);
+    f.render_widget(footer, footer_area);
+
+    // Overlays
+    if app.long_break_prompt {
+        draw_long_break_prompt(f, app, size);
+    }
+    if app.reset_prompt {
+        draw_reset_prompt(f, size);
+    }
+    if app.interrupt_prompt {
+        draw_interrupt_prompt(f, app, size);
+    }
+    if app.resume_task_prompt {
+        draw_resume_task_prompt(f, app, size);
+    }
+    if let Some(step) = app.tutorial_step {
+        draw_tutorial(f, step, size);
+    }
+}
+
+/// Centers a `width` x `height` rectangle inside `area`, shrinking it if needed.
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
 }
 
+/// Builds the "Controls: [Key] Action | ..." line from the currently available actions.
+fn footer_text(app: &App) -> String {
+    let actions: Vec<String> = app
+        .available_actions()
+        .iter()
+        .map(|(key, action)| format!("[{}] {}", key, action))
+        .collect();
+    format!("Controls: {}", actions.join(" | "))
+}
+
+/// Date and time for the right end of the footer (`show_clock`), or `None`
+/// when it's off or wouldn't fit beside `text` in `width` columns.
+fn footer_clock(app: &App, text: &str, width: u16) -> Option<String> {
+    if !app.config.show_clock {
+        return None;
+    }
+    let format = if app.config.clock_24h {
+        "%a %d %b %H:%M"
+    } else {
+        "%a %d %b %-I:%M %p"
+    };
+    let clock = Local::now().format(format).to_string();
+
+    // Keep a gap between the controls and the clock
+    (text.width() + clock.width() + 2 <= usize::from(width)).then_some(clock)
+}
+
+/// Size the `BigText` clock needs for "00:00" without clipping.
+const BIG_CLOCK_WIDTH: u16 = 39;
+const BIG_CLOCK_HEIGHT: u16 = 8;
+
+/// Rows the Timer tab needs besides the gaps and the clock: phase name, task,
+/// status, gauge and pomodoro count.
+const TIMER_TAB_FIXED_ROWS: u16 = 3 + 3 + 1;
+
 fn draw_timer_tab(f: &mut Frame, app: &App, area: Rect) {
+    // The gaps give way first, so the big clock is never squeezed. Without room
+    // for it (e.g. after a resize), the compact layout uses a one-line clock.
+    let spare = area.height.saturating_sub(TIMER_TAB_FIXED_ROWS);
+    let clock_height = if spare >= BIG_CLOCK_HEIGHT {
+        BIG_CLOCK_HEIGHT
+    } else {
+        1
+    };
+    let gaps = spare.saturating_sub(clock_height);
+    let gap_top = (gaps / 2).min(3);
+    let gap_bottom = (gaps - gap_top).min(4);
+
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Fill(1),   // Top Spring
-            Constraint::Length(1), // Phase Name (e.g., "FOCUS SESSION")
-            Constraint::Length(1), // Status (e.g., "RUNNING")
-            Constraint::Length(4), // Gap (Increased space)
-            Constraint::Length(8), // Big Timer Height
-            Constraint::Length(4), // Gap (Increased space)
-            Constraint::Length(3), // Gauge Height
-            Constraint::Length(1), // Pomodoro Count
-            Constraint::Fill(1),   // Bottom Spring
+            Constraint::Fill(1),              // Top Spring
+            Constraint::Length(1),            // Phase Name (e.g., "FOCUS SESSION")
+            Constraint::Length(1),            // Current Task
+            Constraint::Length(1),            // Status (e.g., "RUNNING")
+            Constraint::Length(gap_top),      // Gap (Increased space)
+            Constraint::Length(clock_height), // Big Timer Height
+            Constraint::Length(gap_bottom),   // Gap (Increased space)
+            Constraint::Length(3),            // Gauge Height
+            Constraint::Length(1),            // Pomodoro Count
+            Constraint::Fill(1),              // Bottom Spring
         ])
         .split(area);
 
     let phase_color = app.phase.color();
 
     // Phase Name
-    let phase_text = Paragraph::new(app.phase.name())
+    let phase_text = Paragraph::new(app.phase_name())
         .style(
             Style::default()
                 .fg(phase_color)
@@ -95,39 +200,65 @@ fn draw_timer_tab(f: &mut Frame, app: &App, area: Rect) {
         .alignment(Alignment::Center);
     f.render_widget(phase_text, layout[1]);
 
+    // Current Task (truncated to fit; the Stats tab shows it in full)
+    if let Some(task) = &app.current_task {
+        let task_text = Paragraph::new(truncate(task, layout[2].width, app.config.ascii))
+            .style(Style::default().fg(Color::White))
+            .alignment(Alignment::Center);
+        f.render_widget(task_text, layout[2]);
+    }
+
     // Status
-    let status_str = if app.running { "RUNNING" } else { "PAUSED" };
-    let status_text = Paragraph::new(format!("[ {} ]", status_str))
-        .style(Style::default().fg(Color::DarkGray))
+    let state = app.timer_state();
+    let status_color = match state {
+        TimerState::Ready => app.config.theme.status_ready,
+        TimerState::Running => app.config.theme.status_running,
+        TimerState::Paused => app.config.theme.status_paused,
+    };
+    let mut status_parts = vec![if app.interrupted {
+        "INTERRUPTED: resume with Space"
+    } else {
+        state.label()
+    }];
+    if app.deep_work {
+        status_parts.push("DEEP WORK");
+    }
+    let status_str = format!("[ {} ]", status_parts.join(" | "));
+    let status_text = Paragraph::new(status_str)
+        .style(Style::default().fg(status_color))
         .alignment(Alignment::Center);
-    f.render_widget(status_text, layout[2]);
+    f.render_widget(status_text, layout[3]);
 
     // Big Timer
-    let remaining = app.get_remaining();
-    let mins = remaining.as_secs() / 60;
-    let secs = remaining.as_secs() % 60;
-    let time_str = format!("{:02}:{:02}", mins, secs);
-
-    let timer_layout = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Fill(1),    // Spacer Left
-            Constraint::Length(39), // Fixed width for "00:00"
-            Constraint::Fill(1),    // Spacer Right
-        ])
-        .split(layout[4]);
+    let timer_color = if app.running {
+        phase_color
+    } else {
+        Color::White
+    };
+    draw_big_clock(f, app, Style::default().fg(timer_color), layout[5]);
 
-    let big_text = BigText::builder()
-        .pixel_size(PixelSize::Full)
-        .style(Style::default().fg(if app.running {
-            phase_color
+    // Break Quote, in the gap under the timer
+    if let Some(quote) = &app.break_quote {
+        let quote_area = Rect {
+            y: layout[6].y + 1,
+            height: layout[6].height.saturating_sub(1),
+            ..layout[6]
+        };
+        let quote = if app.config.ascii {
+            quote.replace('—', "-")
         } else {
-            Color::White
-        }))
-        .lines(vec![time_str.into()])
-        .build();
-
-    f.render_widget(big_text, timer_layout[1]);
+            quote.clone()
+        };
+        let quote_text = Paragraph::new(quote)
+            .style(
+                Style::default()
+                    .fg(Color::DarkGray)
+                    .add_modifier(Modifier::ITALIC),
+            )
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        f.render_widget(quote_text, quote_area);
+    }
 
     // Progress Gauge
     let gauge_layout = Layout::default()
@@ -137,33 +268,246 @@ fn draw_timer_tab(f: &mut Frame, app: &App, area: Rect) {
             Constraint::Percentage(80), // 80% width
             Constraint::Fill(1),
         ])
-        .split(layout[6]);
+        .split(layout[7]);
 
-    let total = app.get_target_duration().as_secs_f64();
-    let elapsed = app.get_elapsed().as_secs_f64();
-    let ratio = (elapsed / total).clamp(0.0, 1.0);
+    let ratio = app.gauge_ratio();
+    let gauge_title = if app.gauge_inverted {
+        " Time Remaining "
+    } else {
+        " Time Elapsed "
+    };
 
-    let gauge = Gauge::default()
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(" Time Elapsed "),
-        )
-        .gauge_style(Style::default().fg(phase_color))
-        .ratio(ratio)
-        .label(format!("{:.0}%", ratio * 100.0));
+    let gauge = PhaseGauge {
+        block: Block::default().borders(Borders::ALL).title(gauge_title),
+        ratio,
+        label: gauge_label(app, ratio),
+        symbol: app.phase.gauge_symbol(app.config.ascii),
+        style: Style::default().fg(phase_color),
+        unfilled_style: Style {
+            bg: app.config.theme.gauge_unfilled,
+            ..Style::default()
+        },
+    };
 
     f.render_widget(gauge, gauge_layout[1]);
 
-    // Pomodoro Count
-    let count_text = Paragraph::new(format!(
-        "Pomodoros Completed: {}/{}",
-        app.pomodoro_count % app.long_break_interval,
-        app.long_break_interval
-    ))
-    .style(Style::default().fg(Color::DarkGray))
-    .alignment(Alignment::Center);
-    f.render_widget(count_text, layout[7]);
+    // Pomodoro Count (a plain countdown has no cycle)
+    if app.plain {
+        return;
+    }
+    let count_str = match app.config.cycle_display {
+        CycleDisplay::Count => format!(
+            "Pomodoros Completed: {}/{}",
+            app.pomodoro_count % app.long_break_interval,
+            app.long_break_interval
+        ),
+        CycleDisplay::Combined => round_progress(app),
+    };
+    let count_text = Paragraph::new(count_str)
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(count_text, layout[8]);
+}
+
+/// The remaining time in large digits: `BigText` blocks by default, or a figlet
+/// font (`timer_font`). A figlet rendering that doesn't fit falls back to the
+/// blocks.
+fn draw_big_clock(f: &mut Frame, app: &App, style: Style, area: Rect) {
+    let time_str = app.remaining_clock();
+
+    if let Some(lines) = figlet_lines(app.config.timer_font, &time_str)
+        && lines
+            .iter()
+            .all(|line| line.width() <= usize::from(area.width))
+        && lines.len() <= usize::from(area.height)
+    {
+        // Center vertically; every line is padded to the same width, so the
+        // digits stay aligned when centered horizontally
+        let top = (area.height - lines.len() as u16) / 2;
+        let figure = Paragraph::new(lines.into_iter().map(Line::from).collect::<Vec<_>>())
+            .style(style)
+            .alignment(Alignment::Center);
+        f.render_widget(
+            figure,
+            Rect {
+                y: area.y + top,
+                height: area.height - top,
+                ..area
+            },
+        );
+        return;
+    }
+
+    // Too small for the big digits (e.g. after a resize): plain text instead
+    if area.width < BIG_CLOCK_WIDTH || area.height < BIG_CLOCK_HEIGHT {
+        let clock = Paragraph::new(time_str)
+            .style(style.add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center);
+        f.render_widget(
+            clock,
+            Rect {
+                y: area.y + area.height / 2,
+                height: area.height.min(1),
+                ..area
+            },
+        );
+        return;
+    }
+
+    let timer_layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Fill(1),                 // Spacer Left
+            Constraint::Length(BIG_CLOCK_WIDTH), // Fixed width for "00:00"
+            Constraint::Fill(1),                 // Spacer Right
+        ])
+        .split(area);
+
+    let big_text = BigText::builder()
+        .pixel_size(PixelSize::Full)
+        .style(style)
+        .lines(vec![time_str.into()])
+        .build();
+    f.render_widget(big_text, timer_layout[1]);
+}
+
+/// Renders `text` with a bundled figlet font, trimmed of blank rows and padded
+/// to a common width. `None` for the default `BigText` font.
+fn figlet_lines(font: TimerFont, text: &str) -> Option<Vec<String>> {
+    let font = match font {
+        TimerFont::BigText => return None,
+        TimerFont::Standard => FIGlet::standard(),
+        TimerFont::Small => FIGlet::small(),
+        TimerFont::Big => FIGlet::big(),
+        TimerFont::Slant => FIGlet::slant(),
+    }
+    .ok()?;
+    let figure = font.convert(text)?.as_str();
+
+    let lines: Vec<&str> = figure
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|line| line.is_empty())
+        .collect();
+    let end = lines.iter().rposition(|line| !line.is_empty())? + 1;
+    let width = lines[..end].iter().map(|line| line.width()).max()?;
+    Some(
+        lines[..end]
+            .iter()
+            .map(|line| format!("{:<width$}", line))
+            .collect(),
+    )
+}
+
+/// Shortens `text` to at most `width` columns, ending in "…" ("..." in ASCII
+/// mode) when cut.
+fn truncate(text: &str, width: u16, ascii: bool) -> String {
+    let width = usize::from(width);
+    if text.width() <= width {
+        return text.to_string();
+    }
+
+    let ellipsis = if ascii { "..." } else { "…" };
+    if width < ellipsis.width() {
+        return ".".repeat(width);
+    }
+
+    let mut out = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > width - ellipsis.width() {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push_str(ellipsis);
+    out
+}
+
+/// Combined focus/break progression for the current round, e.g. "F✓ B✓ F✓ B✓ F⋯".
+/// Finished phases are marked done (✓) or skipped (✗); the current one is pending (⋯).
+fn round_progress(app: &App) -> String {
+    let (done, skipped, pending) = if app.config.ascii {
+        ("+", "-", "..")
+    } else {
+        ("✓", "✗", "⋯")
+    };
+    let letter = |phase: Phase| if phase.is_break() { "B" } else { "F" };
+
+    let today = stats::today(&app.history);
+    let mut steps: Vec<String> = stats::current_round(&today)
+        .iter()
+        .map(|record| {
+            let mark = if record.completed { done } else { skipped };
+            format!("{}{}", letter(record.phase), mark)
+        })
+        .collect();
+    steps.push(format!("{}{}", letter(app.phase), pending));
+    steps.join(" ")
+}
+
+/// Gauge label: percent or remaining time, optionally prefixed with the configured
+/// icon. Non-ASCII icons are dropped in ASCII mode.
+fn gauge_label(app: &App, ratio: f64) -> String {
+    let value = match app.config.gauge_label {
+        GaugeLabel::Percent => format!("{:.0}%", ratio * 100.0),
+        GaugeLabel::Remaining => app.remaining_clock(),
+    };
+
+    let icon = &app.config.gauge_icon;
+    if icon.is_empty() || (app.config.ascii && !icon.is_ascii()) {
+        value
+    } else {
+        format!("{} {}", icon, value)
+    }
+}
+
+/// Progress bar that fills with a per-phase symbol. ratatui's `Gauge` always
+/// fills with a solid block, which makes every phase look the same.
+struct PhaseGauge<'a> {
+    block: Block<'a>,
+    ratio: f64,
+    label: String,
+    symbol: &'static str,
+    style: Style,          // Filled portion
+    unfilled_style: Style, // The rest of the bar
+}
+
+impl Widget for PhaseGauge<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.block.inner(area);
+        self.block.render(area, buf);
+        if inner.is_empty() {
+            return;
+        }
+
+        // Filled portion, then the rest
+        let filled = (f64::from(inner.width) * self.ratio).round() as u16;
+        for y in inner.top()..inner.bottom() {
+            for x in inner.left()..inner.left() + filled {
+                buf[(x, y)].set_symbol(self.symbol).set_style(self.style);
+            }
+            for x in inner.left() + filled..inner.right() {
+                buf[(x, y)].set_style(self.unfilled_style);
+            }
+        }
+
+        // Label, centered on the middle row
+        let label_width = (Span::raw(&self.label).width() as u16).min(inner.width);
+        let x = inner.left() + (inner.width - label_width) / 2;
+        let y = inner.top() + inner.height / 2;
+        buf.set_stringn(
+            x,
+            y,
+            &self.label,
+            label_width as usize,
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
 }
 
 fn draw_settings_tab(f: &mut Frame, app: &App, area: Rect) {
@@ -175,6 +519,31 @@ fn draw_settings_tab(f: &mut Frame, app: &App, area: Rect) {
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
+    // The timer keeps going while settings are open: make that obvious
+    if app.running && app.config.running_banner {
+        let icon = if app.config.ascii { "" } else { "⏱ " };
+        let banner = Paragraph::new(format!(
+            "{}Timer running: {} {}",
+            icon,
+            app.phase_name(),
+            app.remaining_clock()
+        ))
+        .style(
+            Style::default()
+                .fg(app.config.theme.banner_fg)
+                .bg(app.config.theme.banner_bg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center);
+        f.render_widget(
+            banner,
+            Rect {
+                height: 1,
+                ..inner_area
+            },
+        );
+    }
+
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -195,14 +564,17 @@ fn draw_settings_tab(f: &mut Frame, app: &App, area: Rect) {
 
             let style = if is_selected {
                 Style::default()
-                    .fg(Color::Yellow)
-                    .bg(Color::DarkGray)
+                    .fg(app.config.theme.selected_fg)
+                    .bg(app.config.theme.selected_bg)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default().fg(Color::White)
             };
 
-            let text = format!(" {}   < {:02} min > ", label, value);
+            let mut text = format!(" {}   < {:02} min > ", label, value);
+            if is_selected && let Some(limit) = app.clamp_hint() {
+                text.push_str(&format!("({})", limit));
+            }
             let p = Paragraph::new(text)
                 .block(Block::default().borders(Borders::BOTTOM))
                 .style(style)
@@ -210,6 +582,12 @@ fn draw_settings_tab(f: &mut Frame, app: &App, area: Rect) {
             f.render_widget(p, layout[index]);
         };
 
+    if app.plain {
+        let label = format!("{} Duration", app.config.plain_timer_name);
+        render_setting(f, &label, app.cfg_plain, app.selected_setting, 1);
+        return;
+    }
+
     render_setting(
         f,
         "Focus Duration",
@@ -232,3 +610,559 @@ fn draw_settings_tab(f: &mut Frame, app: &App, area: Rect) {
         3,
     );
 }
+
+/// Sessions listed under "Recent Sessions" on the Stats tab.
+const RECENT_SESSIONS: usize = 5;
+
+fn draw_stats_tab(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Statistics ")
+        .style(Style::default().fg(Color::Magenta));
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let today = stats::today(&app.history);
+
+    let mut rows = vec![
+        (
+            "Pomodoros Today",
+            stats::pomodoros(&today).count().to_string(),
+        ),
+        (
+            "Focused Today",
+            stats::format_minutes(stats::focus_time(&today)),
+        ),
+        (
+            "Pomodoros All Time",
+            stats::pomodoros(&app.history).count().to_string(),
+        ),
+        (
+            "Best Focus",
+            stats::format_minutes(stats::best_focus_streak(&app.history)),
+        ),
+    ];
+    if app.config.show_attempts {
+        let attempts = stats::attempts(&today).count();
+        let interrupted = attempts - stats::pomodoros(&today).count();
+        rows.insert(
+            1,
+            (
+                "Attempts Today",
+                format!("{} ({} interrupted)", attempts, interrupted),
+            ),
+        );
+    }
+    let interruptions: usize = today.iter().map(|record| record.interruptions.len()).sum();
+    if interruptions > 0 {
+        rows.push(("Interruptions Today", interruptions.to_string()));
+    }
+    if let Some(adherence) = stats::break_adherence(&today) {
+        rows.push(("Break Adherence Today", format!("{}%", adherence)));
+    }
+    if app.config.rest_debt_mins > 0 {
+        rows.push((
+            "Rest Debt Today",
+            stats::format_minutes(stats::rest_debt(&today)),
+        ));
+    }
+
+    let mut lines: Vec<Line> = Vec::new();
+    for (label, value) in rows {
+        lines.push(Line::from(format!("{}: {}", label, value)));
+        lines.push(Line::default());
+    }
+
+    // Full task name (the Timer tab truncates long ones)
+    if let Some(task) = &app.current_task {
+        lines.push(Line::from(format!("Current Task: {}", task)));
+        lines.push(Line::default());
+    }
+
+    // Daily goal pace
+    if app.config.daily_goal > 0 {
+        let done = stats::pomodoros(&today).count() as u32;
+        let goal = app.config.daily_goal;
+        let pace = stats::pace(
+            done,
+            goal,
+            Local::now().time(),
+            app.config.day_start,
+            app.config.day_end,
+        );
+        let (text, color) = match pace {
+            stats::Pace::NotStarted => ("Not started yet".to_string(), Color::DarkGray),
+            stats::Pace::OnTrack => ("On track".to_string(), Color::Green),
+            stats::Pace::Behind(1) => ("Behind by 1 session".to_string(), Color::Yellow),
+            stats::Pace::Behind(n) => (format!("Behind by {} sessions", n), Color::Red),
+            stats::Pace::GoalReached => ("Goal reached!".to_string(), Color::Green),
+        };
+        lines.push(Line::from(vec![
+            Span::raw(format!("Daily Goal: {}/{} | Pace: ", done, goal)),
+            Span::styled(
+                text,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        lines.push(Line::default());
+    }
+
+    // Profile leaderboard, only worth showing once several profiles are in use
+    let leaderboard = stats::pomodoros_by_profile(&today);
+    if leaderboard.len() > 1 {
+        lines.push(Line::styled(
+            "Today by Profile",
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        for (rank, (profile, count)) in leaderboard.iter().enumerate() {
+            lines.push(Line::from(format!(
+                "{}. {:<12} {:>3}",
+                rank + 1,
+                profile,
+                count
+            )));
+        }
+        lines.push(Line::default());
+    }
+
+    // Planned vs actual for the last few sessions; interrupted ones stand out
+    let recent = &app.history[app.history.len().saturating_sub(RECENT_SESSIONS)..];
+    if !recent.is_empty() {
+        lines.push(Line::styled(
+            "Recent Sessions",
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        for record in recent.iter().rev() {
+            let style = if record.completed {
+                Style::default()
+            } else {
+                Style::default().fg(Color::Yellow)
+            };
+            lines.push(Line::styled(
+                format!(
+                    "{} {:<11} {:>3}m / {:>3}m {:>4}%",
+                    record.started_at.format("%H:%M"),
+                    record.phase.short_name(),
+                    record.active_secs / 60,
+                    record.planned_secs / 60,
+                    record.adherence()
+                ),
+                style,
+            ));
+        }
+    }
+
+    // Rows needed once long lines (e.g. the task name) wrap
+    let text_width = usize::from(inner_area.width.saturating_sub(4).max(1));
+    let height: usize = lines
+        .iter()
+        .map(|line| line.width().div_ceil(text_width).max(1))
+        .sum();
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Fill(1),
+            Constraint::Length(height as u16),
+            Constraint::Fill(1),
+        ])
+        .margin(2)
+        .split(inner_area);
+
+    let p = Paragraph::new(lines)
+        .style(Style::default().fg(Color::White))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+    f.render_widget(p, layout[1]);
+}
+
+fn draw_lockout(f: &mut Frame, app: &App, area: Rect) {
+    let phase_color = app.phase.color();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(phase_color))
+        .style(Style::default().bg(Color::Black));
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Fill(1),   // Top Spring
+            Constraint::Length(1), // Phase Name
+            Constraint::Length(1), // Message
+            Constraint::Length(2), // Gap
+            Constraint::Length(8), // Big Timer Height
+            Constraint::Length(2), // Gap
+            Constraint::Length(1), // Controls
+            Constraint::Fill(1),   // Bottom Spring
+        ])
+        .split(inner_area);
+
+    let phase_text = Paragraph::new(app.phase_name())
+        .style(
+            Style::default()
+                .fg(phase_color)
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center);
+    f.render_widget(phase_text, layout[1]);
+
+    let message = match app.timer_state() {
+        TimerState::Ready => "Time for a break. Press [Space] to start resting.",
+        TimerState::Running => "Step away from the screen. The break can't be skipped.",
+        TimerState::Paused => "Break paused. Press [Space] to continue resting.",
+    };
+    let message_text = Paragraph::new(message)
+        .style(Style::default().fg(Color::White))
+        .alignment(Alignment::Center);
+    f.render_widget(message_text, layout[2]);
+
+    draw_big_clock(f, app, Style::default().fg(phase_color), layout[4]);
+
+    let controls = Paragraph::new(footer_text(app))
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(controls, layout[6]);
+}
+
+fn draw_tutorial(f: &mut Frame, step: usize, area: Rect) {
+    let (title, body) = TUTORIAL_STEPS[step];
+    let popup = centered_rect(60, 10, area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(
+            " {} ({}/{}) ",
+            title,
+            step + 1,
+            TUTORIAL_STEPS.len()
+        ))
+        .style(Style::default().fg(Color::Cyan).bg(Color::Black));
+    let inner_area = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .margin(1)
+        .split(inner_area);
+
+    let text = Paragraph::new(body)
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: true });
+    f.render_widget(text, layout[0]);
+
+    let next = if step + 1 == TUTORIAL_STEPS.len() {
+        "Done"
+    } else {
+        "Next"
+    };
+    let controls = Paragraph::new(format!("[Left] Back | [Right/Enter] {} | [Esc] Skip", next))
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    f.render_widget(controls, layout[1]);
+}
+
+fn draw_long_break_prompt(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(44, 4, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Round Complete ")
+        .style(
+            Style::default()
+                .fg(Phase::LongBreak.color())
+                .bg(Color::Black),
+        );
+    let inner_area = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let text = Paragraph::new(vec![
+        Line::from(format!("Long break ({}m)?", app.cfg_long)),
+        Line::from("[S] Skip / [Enter] Start").style(Style::default().fg(Color::DarkGray)),
+    ])
+    .alignment(Alignment::Center);
+    f.render_widget(text, inner_area);
+}
+
+fn draw_reset_prompt(f: &mut Frame, area: Rect) {
+    let popup = centered_rect(50, 5, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Reset Everything? ")
+        .style(Style::default().fg(Color::Red).bg(Color::Black));
+    let inner_area = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let text = Paragraph::new(vec![
+        Line::from("Restore every setting to its default."),
+        Line::from("[Y] Reset / [D] Reset & delete config file")
+            .style(Style::default().fg(Color::DarkGray)),
+        Line::from("[Esc] Cancel").style(Style::default().fg(Color::DarkGray)),
+    ])
+    .alignment(Alignment::Center);
+    f.render_widget(text, inner_area);
+}
+
+fn draw_resume_task_prompt(f: &mut Frame, app: &App, area: Rect) {
+    let popup = centered_rect(50, 4, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Welcome Back ")
+        .style(Style::default().fg(Color::Cyan).bg(Color::Black));
+    let inner_area = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let task = app.last_task.as_deref().unwrap_or_default();
+    let question = format!("Continue working on '{}'?", task);
+    let text = Paragraph::new(vec![
+        Line::from(truncate(&question, inner_area.width, app.config.ascii)),
+        Line::from("[Y] Continue / [N] New session").style(Style::default().fg(Color::DarkGray)),
+    ])
+    .alignment(Alignment::Center);
+    f.render_widget(text, inner_area);
+}
+
+fn draw_interrupt_prompt(f: &mut Frame, app: &App, area: Rect) {
+    let reasons: Vec<&String> = app.config.interrupt_reasons.iter().take(9).collect();
+    let popup = centered_rect(40, reasons.len() as u16 + 4, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Interrupted ")
+        .style(Style::default().fg(Color::Yellow).bg(Color::Black));
+    let inner_area = block.inner(popup);
+    f.render_widget(Clear, popup);
+    f.render_widget(block, popup);
+
+    let mut lines = vec![Line::from("What came up?")];
+    for (i, reason) in reasons.iter().enumerate() {
+        lines.push(Line::from(format!("[{}] {}", i + 1, reason)));
+    }
+    lines.push(Line::from("[Esc] No reason").style(Style::default().fg(Color::DarkGray)));
+    f.render_widget(
+        Paragraph::new(lines).alignment(Alignment::Center),
+        inner_area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use ratatui::{Terminal, backend::TestBackend};
+
+    fn test_app() -> App {
+        App::new(Config::default(), Vec::new())
+    }
+
+    #[test]
+    fn gauge_label_prefixes_the_icon_when_set() {
+        let mut app = test_app();
+        assert_eq!(gauge_label(&app, 0.25), "25%");
+
+        app.config.gauge_icon = "🕐".to_string();
+        assert_eq!(gauge_label(&app, 0.25), "🕐 25%");
+        app.config.gauge_label = GaugeLabel::Remaining;
+        assert_eq!(gauge_label(&app, 0.0), "🕐 25:00");
+    }
+
+    #[test]
+    fn gauge_label_drops_non_ascii_icons_in_ascii_mode() {
+        let mut app = test_app();
+        app.config.ascii = true;
+        app.config.gauge_icon = "🍅".to_string();
+        assert_eq!(gauge_label(&app, 0.5), "50%");
+
+        app.config.gauge_icon = "*".to_string();
+        assert_eq!(gauge_label(&app, 0.5), "* 50%");
+    }
+
+    fn record(phase: Phase, completed: bool) -> stats::SessionRecord {
+        let ended_at = Local::now();
+        stats::SessionRecord::new(
+            phase,
+            ended_at,
+            ended_at,
+            std::time::Duration::ZERO,
+            std::time::Duration::ZERO,
+            completed,
+        )
+    }
+
+    #[test]
+    fn round_progress_shows_the_current_round_so_far() {
+        let mut app = test_app();
+        app.history = vec![
+            record(Phase::Focus, true),
+            record(Phase::LongBreak, true),
+            record(Phase::Focus, true),
+            record(Phase::ShortBreak, true),
+            record(Phase::Focus, true),
+            record(Phase::ShortBreak, false),
+        ];
+        assert_eq!(round_progress(&app), "F✓ B✓ F✓ B✗ F⋯");
+
+        app.phase = Phase::ShortBreak;
+        app.config.ascii = true;
+        assert_eq!(round_progress(&app), "F+ B+ F+ B- B..");
+    }
+
+    #[test]
+    fn truncate_keeps_text_that_fits() {
+        assert_eq!(truncate("Refactor parser", 15, false), "Refactor parser");
+        assert_eq!(truncate("Refactor parser", 80, true), "Refactor parser");
+    }
+
+    #[test]
+    fn truncate_ends_cut_text_with_an_ellipsis() {
+        assert_eq!(truncate("Refactor parser", 9, false), "Refactor…");
+        assert_eq!(truncate("Refactor parser", 10, true), "Refacto...");
+    }
+
+    #[test]
+    fn truncate_handles_very_narrow_widths() {
+        assert_eq!(truncate("Refactor", 0, false), "");
+        assert_eq!(truncate("Refactor", 1, false), "…");
+        assert_eq!(truncate("Refactor", 2, false), "R…");
+        assert_eq!(truncate("Refactor", 0, true), "");
+        assert_eq!(truncate("Refactor", 2, true), "..");
+        assert_eq!(truncate("Refactor", 3, true), "...");
+    }
+
+    #[test]
+    fn truncate_never_splits_wide_characters() {
+        // Each character is two columns wide
+        assert_eq!(truncate("日本語テキスト", 5, false), "日本…");
+        assert_eq!(truncate("日本語テキスト", 4, false), "日…");
+        assert!(truncate("日本語テキスト", 6, true).width() <= 6);
+    }
+
+    #[test]
+    fn footer_clock_is_dropped_when_it_would_not_fit() {
+        let mut app = test_app();
+        let text = "Controls: [Q] Quit";
+        assert_eq!(footer_clock(&app, text, 200), None);
+
+        app.config.show_clock = true;
+        let clock = footer_clock(&app, text, 200).unwrap();
+        // "Thu 15 Oct 14:05": text, two columns of gap, then the clock
+        let fits = (text.width() + clock.width() + 2) as u16;
+        assert!(footer_clock(&app, text, fits).is_some());
+        assert_eq!(footer_clock(&app, text, fits - 1), None);
+        assert_eq!(footer_clock(&app, text, 10), None);
+    }
+
+    /// Renders the whole UI at `width` x `height` and returns it as text.
+    fn render(app: &App, width: u16, height: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        terminal.draw(|f| ui(f, app)).unwrap();
+        crate::snapshot::to_text(terminal.backend().buffer())
+    }
+
+    #[test]
+    fn settings_banner_shows_while_the_timer_runs() {
+        let mut app = test_app();
+        app.current_tab = AppTab::Settings;
+        assert!(!render(&app, 80, 30).contains("Timer running"));
+
+        app.toggle_timer();
+        let screen = render(&app, 80, 30);
+        assert!(screen.contains("⏱ Timer running: FOCUS SESSION 2"));
+
+        app.config.running_banner = false;
+        assert!(!render(&app, 80, 30).contains("Timer running"));
+    }
+
+    #[test]
+    fn figlet_fonts_render_aligned_digits() {
+        assert_eq!(figlet_lines(TimerFont::BigText, "25:00"), None);
+
+        for font in [
+            TimerFont::Standard,
+            TimerFont::Small,
+            TimerFont::Big,
+            TimerFont::Slant,
+        ] {
+            let lines = figlet_lines(font, "25:00").unwrap();
+            assert!(!lines.is_empty(), "{font:?}");
+            assert!(lines.iter().any(|line| !line.trim().is_empty()), "{font:?}");
+            // No blank border rows, and every row padded to the same width
+            assert!(!lines[0].trim().is_empty(), "{font:?}");
+            assert!(!lines[lines.len() - 1].trim().is_empty(), "{font:?}");
+            let width = lines[0].width();
+            assert!(lines.iter().all(|line| line.width() == width), "{font:?}");
+        }
+    }
+
+    #[test]
+    fn gauge_unfilled_portion_has_its_own_style() {
+        let mut app = test_app();
+        app.paused_duration = app.get_target_duration() / 4;
+
+        // Filled and unfilled cells on the gauge's label row
+        let gauge_cells = |app: &App| {
+            let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+            terminal.draw(|f| ui(f, app)).unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            let y = (0..buffer.area.height)
+                .find(|&y| {
+                    (0..buffer.area.width)
+                        .map(|x| buffer[(x, y)].symbol())
+                        .collect::<String>()
+                        .contains("25%")
+                })
+                .unwrap();
+            let last_filled = (0..buffer.area.width)
+                .filter(|&x| buffer[(x, y)].symbol() == "█")
+                .max()
+                .unwrap();
+            (
+                buffer[(last_filled, y)].clone(),
+                buffer[(last_filled + 1, y)].clone(),
+            )
+        };
+
+        // Unset: the unfilled portion keeps the main block's background
+        let (filled, unfilled) = gauge_cells(&app);
+        assert_eq!(unfilled.symbol(), " ");
+        assert_eq!(unfilled.bg, Color::Black);
+        assert_eq!(filled.bg, Color::Black);
+
+        app.config.theme.gauge_unfilled = Some(Color::Blue);
+        let (filled, unfilled) = gauge_cells(&app);
+        assert_eq!(unfilled.bg, Color::Blue);
+        assert_eq!(filled.bg, Color::Black);
+        assert_ne!(filled.style(), unfilled.style());
+    }
+
+    #[test]
+    fn timer_tab_falls_back_to_a_one_line_clock_when_resized_small() {
+        let app = test_app();
+        let mut terminal = Terminal::new(TestBackend::new(80, 40)).unwrap();
+        let mut draw_at = |width, height| {
+            terminal.backend_mut().resize(width, height);
+            terminal.draw(|f| ui(f, &app)).unwrap();
+            crate::snapshot::to_text(terminal.backend().buffer())
+        };
+
+        let big = draw_at(80, 40);
+        assert!(big.contains('█'));
+        assert!(!big.contains("25:00"));
+
+        // Too short, then too narrow for the block digits
+        for (width, height) in [(80, 18), (36, 40)] {
+            let compact = draw_at(width, height);
+            assert!(compact.contains("25:00"), "{width}x{height}");
+            assert!(compact.contains("FOCUS"), "{width}x{height}");
+            assert!(!compact.contains('█'), "{width}x{height}");
+        }
+
+        // Back to the big clock once there is room again
+        assert_eq!(draw_at(80, 40), big);
+    }
+}
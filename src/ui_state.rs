@@ -0,0 +1,106 @@
+use serde::{Deserialize, Deserializer, Serialize, de::DeserializeOwned};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::app::{AppTab, SettingSelection};
+
+// --- UI State ---
+
+/// Where the app was left on quit, restored on the next launch. Kept in the
+/// data directory rather than the config file, which stays hand-edited.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    #[serde(deserialize_with = "lenient")]
+    pub last_tab: Option<AppTab>,
+    #[serde(deserialize_with = "lenient")]
+    pub last_setting: Option<SettingSelection>,
+    pub last_task: Option<String>, // Offered again on the next launch
+    pub tutorial_seen: bool,       // First-run tutorial completed or skipped
+}
+
+/// A value that doesn't name a known tab or setting restores nothing instead
+/// of failing the whole file.
+fn lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Ok(serde_json::from_value(value).ok())
+}
+
+pub fn state_path() -> Option<PathBuf> {
+    if cfg!(test) {
+        return None;
+    }
+    dirs::data_dir().map(|dir| dir.join("pomodoro-tui").join("ui.json"))
+}
+
+/// Reads the saved state. A missing or unreadable file restores nothing.
+pub fn load() -> UiState {
+    state_path().map(|path| read(&path)).unwrap_or_default()
+}
+
+fn read(path: &Path) -> UiState {
+    fs::read_to_string(path)
+        .map(|contents| parse(&contents))
+        .unwrap_or_default()
+}
+
+fn parse(contents: &str) -> UiState {
+    serde_json::from_str(contents).unwrap_or_default()
+}
+
+pub fn save(state: &UiState) -> io::Result<()> {
+    match state_path() {
+        Some(path) => write(&path, state),
+        None => Ok(()),
+    }
+}
+
+fn write(path: &Path, state: &UiState) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string(state)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_values_fall_back_to_the_defaults() {
+        let state = parse(r#"{"last_tab": "History", "last_setting": 3, "last_task": "Docs"}"#);
+        assert_eq!(state.last_tab, None);
+        assert_eq!(state.last_setting, None);
+        assert_eq!(state.last_task.as_deref(), Some("Docs"));
+
+        assert_eq!(parse("not json"), UiState::default());
+    }
+
+    #[test]
+    fn saved_state_loads_back() {
+        let dir =
+            std::env::temp_dir().join(format!("pomodoro-tui-ui-state-{}", std::process::id()));
+        let path = dir.join("ui.json");
+        assert_eq!(read(&path), UiState::default());
+
+        let state = UiState {
+            last_tab: Some(AppTab::Settings),
+            last_setting: Some(SettingSelection::LongBreakTime),
+            last_task: Some("Refactor parser".to_string()),
+            tutorial_seen: true,
+        };
+        write(&path, &state).unwrap();
+        assert_eq!(read(&path), state);
+
+        // No task when quitting: nothing to offer next time
+        write(&path, &UiState::default()).unwrap();
+        assert_eq!(read(&path).last_task, None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}